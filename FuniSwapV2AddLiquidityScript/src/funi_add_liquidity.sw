@@ -0,0 +1,28 @@
+script;
+
+use std::asset::transfer;
+
+use funi_lib::{
+    errors::RouterError,
+    pair::FuniSwapV2Pair,
+};
+
+// Transfers amount0 of asset0 and amount1 of asset1 from the transaction inputs to
+// the pair and mints the LP tokens to `to`, in a single transaction. Nobody can call
+// mint() between the transfers and the mint, so the deposit can't be taken.
+// Returns the liquidity minted.
+fn main(
+    pair: ContractId,
+    asset0: AssetId,
+    amount0: u64,
+    asset1: AssetId,
+    amount1: u64,
+    min_liquidity: u64,
+    to: Identity,
+) -> u64 {
+    transfer(Identity::ContractId(pair), asset0, amount0);
+    transfer(Identity::ContractId(pair), asset1, amount1);
+    let liquidity = abi(FuniSwapV2Pair, pair.bits()).mint(to);
+    require(liquidity >= min_liquidity, RouterError::InsufficientLiquidityMinted);
+    liquidity
+}
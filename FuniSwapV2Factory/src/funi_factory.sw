@@ -1,40 +1,58 @@
 contract;
 
 use std::{
-    asset::{
-        burn,
-        mint_to,
-        transfer,
-    },
-    call_frames::msg_asset_id,
-    context::msg_amount,
-    context::this_balance,
-    constants::DEFAULT_SUB_ID,
-    string::String,
     storage::*,
-    storage::storage_api::{
-        read, 
-        write
-    },
     storage::storage_map::*,
     hash::*,
     asset_id::*,
 };
 
-pub const IDENTICAL_ADDRESSES_SIGNAL = 0xffff_ffff_fffe_0000;
+use funi_lib::{
+    errors::FactoryError,
+    factory::FuniSwapV2Factory,
+    pair::FuniSwapV2Pair,
+};
 
-abi FuniSwapV2Factory {
-    fn create_pair(token0: AssetId, token1: AssetId) -> ContractId;
+storage {
+    // Fuel contracts cannot deploy other contracts, so unlike the UniswapV2Factory
+    // we don't create the pair here. The pair is deployed off-chain with the sorted
+    // tokens as configurables and then registered under its (token0, token1, stable)
+    // key, so that a volatile and a stable pair of the same tokens can both exist.
+    pairs: StorageMap<(AssetId, AssetId, bool), ContractId> = StorageMap {},
 }
 
 impl FuniSwapV2Factory for Contract {
-    fn create_pair(_token0: AssetId, _token1: AssetId) -> ContractId {
-        require(_token0 != _token1, "Identical AssetIds");
-        let mut token0 = _token0;
-        let mut token1 = _token1;
-        if token0.bits() > token1.bits {
-            token0 = _token1;
-            token1 = _token0;
-        }
+    #[storage(read, write)]
+    fn create_pair(_token0: AssetId, _token1: AssetId, stable: bool, pair: ContractId) -> ContractId {
+        require(_token0 != _token1, FactoryError::IdenticalAssetIds);
+        let (token0, token1) = _sort_tokens(_token0, _token1);
+        require(
+            storage.pairs.get((token0, token1, stable)).try_read().is_none(),
+            FactoryError::PairExists
+        );
+        // only register a pair of the given type deployed for these tokens and this factory
+        let funi_pair = abi(FuniSwapV2Pair, pair.bits());
+        require(funi_pair.stable() == stable, FactoryError::PairTypeMismatch);
+        require(
+            funi_pair.token0() == token0 && funi_pair.token1() == token1,
+            FactoryError::PairTokensMismatch
+        );
+        require(funi_pair.factory() == ContractId::this(), FactoryError::PairFactoryMismatch);
+        storage.pairs.insert((token0, token1, stable), pair);
+        pair
+    }
+
+    #[storage(read)]
+    fn get_pair(token0: AssetId, token1: AssetId, stable: bool) -> Option<ContractId> {
+        let (token0, token1) = _sort_tokens(token0, token1);
+        storage.pairs.get((token0, token1, stable)).try_read()
+    }
+}
+
+fn _sort_tokens(token_a: AssetId, token_b: AssetId) -> (AssetId, AssetId) {
+    if token_a.bits() > token_b.bits() {
+        (token_b, token_a)
+    } else {
+        (token_a, token_b)
     }
 }
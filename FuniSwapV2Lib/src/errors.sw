@@ -0,0 +1,69 @@
+library;
+
+// Errors logged by require() on a revert. The abigen bindings decode them, so the
+// Rust side can match on the variant instead of a message.
+
+/// Errors of FuniSwapV2Pair and FuniSwapV2StablePair.
+pub enum PairError {
+    /// token0 or token1 is an unset configurable.
+    ZeroAssetId: (),
+    /// token0 is not lower than token1.
+    UnsortedAssetIds: (),
+    /// decimals0 or decimals1 of the stable pair is an unset configurable.
+    UnsetDecimals: (),
+    /// burn() was sent another asset than the LP token.
+    IncorrectAsset: (),
+    /// get_amount_out() was given an asset the pair doesn't trade.
+    InvalidAsset: (),
+    InsufficientLiquidity: (),
+    InsufficientLiquiditySent: (),
+    InsufficientLiquidityBurned: (),
+    InsufficientOutputAmount: (),
+    InsufficientInputAmount: (),
+    KInvariantIncorrect: (),
+    /// The first deposit of the stable pair is below its MINIMUM_K invariant.
+    InsufficientK: (),
+    /// Newton's method of the stable curve didn't find y.
+    CurveNotConverged: (),
+}
+
+/// Errors of FuniSwapV2Router02 and of the scripts.
+pub enum RouterError {
+    /// The asset is neither token0 nor token1 of the router.
+    IncorrectAssetId: (),
+    /// The asset sent is not the one the method expects.
+    IncorrectAsset: (),
+    /// The base asset has to be sent with the call.
+    BaseAssetExpected: (),
+    /// The asset is not traded by the pair.
+    InvalidAsset: (),
+    NoDeposits: (),
+    InsufficientDeposit: (),
+    InsufficientAAmount: (),
+    InsufficientBAmount: (),
+    InsufficientAmount: (),
+    InsufficientInputAmount: (),
+    InsufficientOutputAmount: (),
+    InsufficientLiquidity: (),
+    InsufficientLiquidityMinted: (),
+    InsufficientLpOutput: (),
+}
+
+/// Errors of FuniSwapV2Factory.
+pub enum FactoryError {
+    IdenticalAssetIds: (),
+    PairExists: (),
+    PairTokensMismatch: (),
+    PairFactoryMismatch: (),
+    /// The pair is not of the type (stable or volatile) it is registered as.
+    PairTypeMismatch: (),
+}
+
+/// Errors of the math helpers, shared by all the contracts.
+pub enum MathError {
+    /// An amount doesn't fit in a u64.
+    AmountTooHigh: (),
+    /// The result of mul_div() doesn't fit in a u256.
+    Overflow: (),
+    DivisionByZero: (),
+}
@@ -35,4 +35,11 @@ pub struct SwapEvent {
     pub amount0_out: u64,
     /// Amount of token0 sent out
     pub amount1_out: u64,
+}
+
+pub struct SyncEvent {
+    /// Reserve of token0 after the update
+    pub reserve0: u256,
+    /// Reserve of token1 after the update
+    pub reserve1: u256,
 }
\ No newline at end of file
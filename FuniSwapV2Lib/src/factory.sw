@@ -0,0 +1,9 @@
+library;
+
+abi FuniSwapV2Factory {
+    #[storage(read, write)]
+    fn create_pair(token0: AssetId, token1: AssetId, stable: bool, pair: ContractId) -> ContractId;
+
+    #[storage(read)]
+    fn get_pair(token0: AssetId, token1: AssetId, stable: bool) -> Option<ContractId>;
+}
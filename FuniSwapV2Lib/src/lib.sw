@@ -0,0 +1,13 @@
+library;
+
+// Shared by the FuniSwapV2 contracts and scripts: the ABIs they call each other
+// through, the events and errors they log, the math they have in common and the
+// liquidity bookkeeping of the pairs.
+
+pub mod errors;
+pub mod events;
+pub mod math;
+pub mod src20;
+pub mod pair;
+pub mod pool;
+pub mod factory;
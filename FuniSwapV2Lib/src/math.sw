@@ -0,0 +1,139 @@
+library;
+
+use ::errors::MathError;
+
+pub fn min(a: u256, b: u256) -> u256 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// 2^128 - 1, the low half of a u256
+const LOW_MASK: u256 = 0x00000000000000000000000000000000ffffffffffffffffffffffffffffffffu256;
+
+// a * b / c rounded down, with a 512-bit intermediate product: only the result has to
+// fit in a u256. Reverts with DivisionByZero or Overflow instead of a VM panic.
+pub fn mul_div(a: u256, b: u256, c: u256) -> u256 {
+    require(c != 0, MathError::DivisionByZero);
+    if a == 0 || b <= u256::max() / a {
+        return (a * b) / c;
+    }
+    let (hi, lo) = mul_wide(a, b);
+    require(hi < c, MathError::Overflow);
+
+    // long division of hi * 2^256 + lo by c, one bit of lo at a time. The remainder
+    // stays below c, shifting it left can carry out of the u256.
+    let mut remainder = hi;
+    let mut quotient: u256 = 0;
+    let mut i: u64 = 256;
+    while i > 0 {
+        i -= 1;
+        let carry = (remainder >> 255) == 1;
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient = quotient << 1;
+        if carry || remainder >= c {
+            // with the carry the remainder is 2^256 + remainder, which is below 2c
+            remainder = if carry {
+                remainder + (u256::max() - c + 1)
+            } else {
+                remainder - c
+            };
+            quotient = quotient | 1;
+        }
+    }
+    quotient
+}
+
+// a * b as (high, low) u256 halves of the 512-bit product
+pub fn mul_wide(a: u256, b: u256) -> (u256, u256) {
+    let (a1, a0) = (a >> 128, a & LOW_MASK);
+    let (b1, b0) = (b >> 128, b & LOW_MASK);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    // below 3 * 2^128, no overflow
+    let mid = (p00 >> 128) + (p01 & LOW_MASK) + (p10 & LOW_MASK);
+    let lo = ((mid & LOW_MASK) << 128) | (p00 & LOW_MASK);
+    let hi = p11 + (p01 >> 128) + (p10 >> 128) + (mid >> 128);
+    (hi, lo)
+}
+
+// a * b >= c * d, compared on the 512-bit products
+pub fn mul_gte(a: u256, b: u256, c: u256, d: u256) -> bool {
+    let (hi_ab, lo_ab) = mul_wide(a, b);
+    let (hi_cd, lo_cd) = mul_wide(c, d);
+    hi_ab > hi_cd || (hi_ab == hi_cd && lo_ab >= lo_cd)
+}
+
+pub fn to_u64(amount: u256) -> u64 {
+    require(amount <= u64::max().as_u256(), MathError::AmountTooHigh);
+    <u64 as TryFrom<u256>>::try_from(amount).unwrap()
+}
+
+// output of a pair for amount_in, after the 0.3% fee, like UniswapV2Library.getAmountOut().
+// The callers check the input and the reserves with their own errors.
+pub fn get_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    let amount_in_with_fee = amount_in.as_u256() * 997;
+    let numerator = amount_in_with_fee * reserve_out.as_u256();
+    let denominator = reserve_in.as_u256() * 1000 + amount_in_with_fee;
+    require(denominator > 0, MathError::DivisionByZero);
+    to_u64(numerator / denominator)
+}
+
+// The expected values are exact floors of a * b / c, as computed by funi_sdk::model::mul_div.
+
+#[test]
+fn test_mul_wide_max() {
+    let (hi, lo) = mul_wide(u256::max(), u256::max());
+    assert(hi == u256::max() - 1);
+    assert(lo == 1);
+}
+
+#[test]
+fn test_mul_wide() {
+    let (hi, lo) = mul_wide(
+        0x123456789abcdef0fedcba98765432100f1e2d3c4b5a69788796a5b4c3d2e1f0u256,
+        0xfedcba9876543210123456789abcdef000112233445566778899aabbccddeeffu256,
+    );
+    assert(hi == 0x121fa00ad77d74232273b07252ebd6a9a5fc4731658e577d170bcfa6d5a04b17u256);
+    assert(lo == 0x04cf31e8af415ac826b891f72c761868786755422e1902ebd3baa085694c2e10u256);
+}
+
+#[test]
+fn test_mul_div_wide_product() {
+    assert(mul_div(u256::max(), u256::max(), u256::max()) == u256::max());
+    assert(mul_div(u256::max(), u256::max() - 1, u256::max()) == u256::max() - 1);
+    assert(mul_div(u256::max(), 3, 4) == 0xbfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffu256);
+    assert(
+        mul_div(0x5555555555555555555555555555555555555555555555555555555555555555u256, 7, 5)
+            == 0x7777777777777777777777777777777777777777777777777777777777777777u256
+    );
+    // divisor above 2^255, the remainder carries out of the u256 during the division
+    assert(
+        mul_div(
+            0x123456789abcdef0fedcba98765432100f1e2d3c4b5a69788796a5b4c3d2e1f0u256,
+            0xfedcba9876543210123456789abcdef000112233445566778899aabbccddeeffu256,
+            0xffffffffffffffff000000000000000100000000000000000000000000000000u256,
+        ) == 0x121fa00ad77d74233493507d2a694accc86ff7a3b87a2e26aae876cd63b12e70u256
+    );
+}
+
+#[test]
+fn test_mul_gte_wide_product() {
+    assert(mul_gte(u256::max(), u256::max(), u256::max(), u256::max() - 1));
+    assert(!mul_gte(u256::max() - 1, u256::max(), u256::max(), u256::max()));
+}
+
+#[test(should_revert)]
+fn test_mul_div_overflow() {
+    // 2^255 * 6 / 3 = 2^256
+    let _ = mul_div(0x8000000000000000000000000000000000000000000000000000000000000000u256, 6, 3);
+}
+
+#[test(should_revert)]
+fn test_mul_div_zero_divisor() {
+    let _ = mul_div(1, 1, 0);
+}
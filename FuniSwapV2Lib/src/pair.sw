@@ -0,0 +1,54 @@
+library;
+
+/// Everything a frontend needs to render the pool, returned by get_pair_state().
+/// The pair keeps no TWAP price accumulators.
+pub struct PairState {
+    pub token0: AssetId,
+    pub token1: AssetId,
+    pub reserve0: u64,
+    pub reserve1: u64,
+    /// LP supply, including the locked MINIMUM_LIQUIDITY
+    pub total_supply: u256,
+    pub fee_bps: u64,
+    /// invariant of the current reserves, reserve0 * reserve1 for the pair and
+    /// x^3 * y + y^3 * x for the stable pair. Not UniswapV2's kLast: no protocol
+    /// fee is taken, so it isn't tracked.
+    pub k: u256,
+}
+
+/// Implemented by FuniSwapV2Pair and FuniSwapV2StablePair, called by the router
+/// and the scripts.
+abi FuniSwapV2Pair {
+    #[storage(read)]
+    fn get_reserves() -> (u64, u64);
+
+    // Burns the LP tokens sent, or only `liquidity` of them when given, in which
+    // case the rest is sent back to the caller.
+    #[payable]
+    #[storage(read, write)]
+    fn burn(to: Identity, liquidity: Option<u64>) -> (u64, u64);
+
+    #[storage(read, write)]
+    fn mint(to: Identity) -> u64;
+
+    #[storage(read, write)]
+    fn swap(amount0_out: u64, amount1_out: u64, to: Identity);
+
+    fn token0() -> AssetId;
+
+    fn token1() -> AssetId;
+
+    fn minimum_liquidity() -> u64;
+
+    fn factory() -> ContractId;
+
+    // true for FuniSwapV2StablePair, the factory registers both types separately
+    fn stable() -> bool;
+
+    #[storage(read)]
+    fn get_pair_state() -> PairState;
+
+    // What burning `liquidity` LP tokens would give now, without burning them.
+    #[storage(read)]
+    fn get_underlying(liquidity: u64) -> (u64, u64);
+}
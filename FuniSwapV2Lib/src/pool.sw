@@ -0,0 +1,298 @@
+library;
+
+// Liquidity bookkeeping shared by FuniSwapV2Pair and FuniSwapV2StablePair. The
+// pairs only differ by their swap invariant: they pass their storage keys and
+// token configurables to the functions below and keep the curve to themselves.
+
+use std::{
+    asset::{
+        burn,
+        mint,
+        mint_to,
+        transfer,
+    },
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    context::this_balance,
+    constants::DEFAULT_SUB_ID,
+    storage::storage_key::*,
+};
+
+use ::errors::PairError;
+use ::events::{
+    MintEvent,
+    BurnEvent,
+    SwapEvent,
+    SyncEvent,
+};
+use ::math::{
+    min,
+    mul_div,
+    to_u64,
+};
+use ::pair::PairState;
+
+/// LP tokens locked in the pair by the first mint.
+pub const MINIMUM_LIQUIDITY: u256 = 1000;
+
+/// decimals of the LP token
+pub const DECIMALS: u8 = 9;
+
+/// Storage of a pair and its sorted tokens.
+pub struct Pool {
+    pub token0: AssetId,
+    pub token1: AssetId,
+    // the zero AssetId is accepted as a token only when allowed and the base asset
+    pub allow_base: bool,
+    // reserves - deposits turned into liquidity.
+    pub reserve0: StorageKey<u256>,
+    pub reserve1: StorageKey<u256>,
+    // LP supply, including the locked MINIMUM_LIQUIDITY
+    pub total_supply: StorageKey<u256>,
+}
+
+/// Balances and inputs of a swap, for the pair to check its invariant on.
+pub struct SwapBalances {
+    pub reserve0: u256,
+    pub reserve1: u256,
+    pub balance0: u256,
+    pub balance1: u256,
+    pub amount0_in: u256,
+    pub amount1_in: u256,
+}
+
+impl Pool {
+    // token0 and token1 have to be sorted (token0 < token1) and non-zero, unless the
+    // pair allows the base asset and zero is the base asset of the chain. Checked by
+    // every state changing call.
+    pub fn check_tokens(self) {
+        require(
+            _is_set(self.token0, self.allow_base) && _is_set(self.token1, self.allow_base),
+            PairError::ZeroAssetId
+        );
+        require(self.token0.bits() < self.token1.bits(), PairError::UnsortedAssetIds);
+    }
+
+    #[storage(read)]
+    pub fn get_reserves(self) -> (u256, u256) {
+        (
+            self.reserve0.read(),
+            self.reserve1.read()
+        )
+    }
+
+    #[storage(read, write)]
+    pub fn mint(self, to: Identity) -> u64 {
+        self.check_tokens();
+        let total_supply = self.total_supply.read();
+        let mut liquidity: u256 = 0;
+        let (reserve0, reserve1) = self.get_reserves();
+        let balance0 = this_balance(self.token0).as_u256();
+        let balance1 = this_balance(self.token1).as_u256();
+        let amount0 = balance0 - reserve0;
+        let amount1 = balance1 - reserve1;
+
+        if total_supply == 0 {
+            liquidity = (amount0 * amount1).sqrt() - MINIMUM_LIQUIDITY;
+            // permanently lock the first MINIMUM_LIQUIDITY tokens: they are minted to the
+            // pair itself, which never transfers its own LP tokens out.
+            self.total_supply.write(MINIMUM_LIQUIDITY);
+            mint(DEFAULT_SUB_ID, to_u64(MINIMUM_LIQUIDITY));
+        } else {
+            liquidity = min(
+                mul_div(amount0, total_supply, reserve0),
+                mul_div(amount1, total_supply, reserve1)
+            )
+        }
+        require(liquidity > 0, PairError::InsufficientLiquidity);
+        self.mint_lp(to, liquidity);
+        self.update(balance0, balance1);
+
+        log(MintEvent{
+            sender: msg_sender().unwrap(),
+            to,
+            amount0,
+            amount1,
+        });
+
+        to_u64(liquidity)
+    }
+
+    // Burns the LP tokens sent, or only `liquidity` of them when given, in which
+    // case the rest is sent back to the caller.
+    #[storage(read, write)]
+    pub fn burn(self, to: Identity, liquidity: Option<u64>) -> (u64, u64) {
+        self.check_tokens();
+        require(
+            msg_asset_id() == AssetId::default(),
+            PairError::IncorrectAsset,
+        );
+        let sent = msg_amount();
+        let liquidity = match liquidity {
+            Some(liquidity) => {
+                require(liquidity <= sent, PairError::InsufficientLiquiditySent);
+                liquidity
+            },
+            None => sent,
+        };
+        let total_supply = self.total_supply.read();
+        let balance0 = this_balance(self.token0).as_u256();
+        let balance1 = this_balance(self.token1).as_u256();
+
+        let amount0 = mul_div(liquidity.as_u256(), balance0, total_supply);
+        let amount1 = mul_div(liquidity.as_u256(), balance1, total_supply);
+        require(amount0 > 0 && amount1 > 0, PairError::InsufficientLiquidityBurned);
+        self.burn_lp(liquidity.as_u256());
+        transfer(to, self.token0, to_u64(amount0));
+        transfer(to, self.token1, to_u64(amount1));
+        if sent > liquidity {
+            transfer(msg_sender().unwrap(), AssetId::default(), sent - liquidity);
+        }
+
+        self.update(
+            this_balance(self.token0).as_u256(),
+            this_balance(self.token1).as_u256(),
+        );
+
+        log(BurnEvent{
+            sender: msg_sender().unwrap(),
+            to,
+            amount0,
+            amount1,
+        });
+
+        (to_u64(amount0), to_u64(amount1))
+    }
+
+    // Sends the outputs of a swap and measures the inputs. The pair checks its
+    // invariant on the returned balances before calling swap_end().
+    #[storage(read)]
+    pub fn swap_begin(self, amount0_out: u64, amount1_out: u64, to: Identity) -> SwapBalances {
+        self.check_tokens();
+        require(amount0_out > 0 || amount1_out > 0, PairError::InsufficientOutputAmount);
+        let (reserve0, reserve1) = self.get_reserves();
+        require(
+            amount0_out.as_u256() < reserve0 && amount1_out.as_u256() < reserve1,
+            PairError::InsufficientLiquidity
+        );
+        // do we need to check if we don't transfer tokens to token0 or token1 contracts?
+
+        if amount0_out > 0 {
+            transfer(to, self.token0, amount0_out);
+        }
+        if amount1_out > 0 {
+            transfer(to, self.token1, amount1_out);
+        }
+        let balance0 = this_balance(self.token0).as_u256();
+        let balance1 = this_balance(self.token1).as_u256();
+
+        let mut amount0_in: u256 = 0;
+        let mut amount1_in: u256 = 0;
+
+        if balance0 > reserve0 - amount0_out.as_u256() {
+            amount0_in = balance0 - (reserve0 - amount0_out.as_u256());
+        }
+        if balance1 > reserve1 - amount1_out.as_u256() {
+            amount1_in = balance1 - (reserve1 - amount1_out.as_u256());
+        }
+        require(amount0_in > 0 || amount1_in > 0, PairError::InsufficientInputAmount);
+
+        SwapBalances {
+            reserve0,
+            reserve1,
+            balance0,
+            balance1,
+            amount0_in,
+            amount1_in,
+        }
+    }
+
+    #[storage(read, write)]
+    pub fn swap_end(self, swap: SwapBalances, amount0_out: u64, amount1_out: u64, to: Identity) {
+        self.update(swap.balance0, swap.balance1);
+
+        log(SwapEvent{
+            sender: msg_sender().unwrap(),
+            to,
+            amount0_in: swap.amount0_in,
+            amount1_in: swap.amount1_in,
+            amount0_out,
+            amount1_out,
+        });
+    }
+
+    #[storage(read)]
+    pub fn get_pair_state(self, fee_bps: u64, k: u256) -> PairState {
+        let (reserve0, reserve1) = self.get_reserves();
+        PairState {
+            token0: self.token0,
+            token1: self.token1,
+            reserve0: to_u64(reserve0),
+            reserve1: to_u64(reserve1),
+            total_supply: self.total_supply.read(),
+            fee_bps,
+            k,
+        }
+    }
+
+    #[storage(read)]
+    pub fn get_underlying(self, liquidity: u64) -> (u64, u64) {
+        let total_supply = self.total_supply.read();
+        if total_supply == 0 {
+            return (0, 0);
+        }
+        // same amounts as burn()
+        let amount0 = mul_div(liquidity.as_u256(), this_balance(self.token0).as_u256(), total_supply);
+        let amount1 = mul_div(liquidity.as_u256(), this_balance(self.token1).as_u256(), total_supply);
+        (to_u64(amount0), to_u64(amount1))
+    }
+
+    /// SRC20 total_supply() of the LP token
+    #[storage(read)]
+    pub fn lp_total_supply(self, asset: AssetId) -> Option<u64> {
+        if asset == AssetId::default() {
+            <u64 as TryFrom<u256>>::try_from(self.total_supply.read())
+        } else {
+            None
+        }
+    }
+
+    #[storage(read, write)]
+    fn mint_lp(self, recipient: Identity, amount: u256) {
+        // Increment total supply of the asset and mint to the recipient.
+        self.total_supply.write(amount + self.total_supply.read());
+        mint_to(recipient, DEFAULT_SUB_ID, to_u64(amount));
+    }
+
+    #[storage(read, write)]
+    fn burn_lp(self, amount: u256) {
+        self.total_supply.write(self.total_supply.read() - amount);
+        burn(DEFAULT_SUB_ID, to_u64(amount));
+    }
+
+    #[storage(read, write)]
+    fn update(self, balance0: u256, balance1: u256) {
+        self.reserve0.write(balance0);
+        self.reserve1.write(balance1);
+
+        log(SyncEvent{
+            reserve0: balance0,
+            reserve1: balance1,
+        });
+    }
+}
+
+/// SRC20 metadata of the LP token: `value` for the default asset of the pair.
+pub fn lp_metadata<T>(asset: AssetId, value: T) -> Option<T> {
+    if asset == AssetId::default() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+// a zero AssetId is an unset configurable, unless the pair was explicitly deployed
+// for the base asset and it is the base asset of the chain
+fn _is_set(asset: AssetId, allow_base: bool) -> bool {
+    asset.bits() != b256::zero() || (allow_base && asset == AssetId::base())
+}
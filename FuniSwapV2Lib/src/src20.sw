@@ -0,0 +1,31 @@
+library;
+
+use std::string::String;
+
+/// The SRC20 getters of a native asset, implemented by the tokens and by the pairs
+/// for their LP token.
+abi SRC20 {
+    #[storage(read)]
+    fn total_assets() -> u64;
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64>;
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String>;
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String>;
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8>;
+}
+
+/// transfer() of a token taking a fee on transfers, like the SRC20Taxed test token.
+/// Native coin transfers can't be taxed, so such a token moves the coins sent with the
+/// call to `to` itself, minus its fee. Returns the amount `to` received.
+abi TaxedTransfer {
+    #[payable]
+    #[storage(read, write)]
+    fn transfer(to: Identity) -> u64;
+}
@@ -1,32 +1,29 @@
 contract;
 
-mod events;
-
 use std::{
-    asset::{
-        burn,
-        mint_to,
-        transfer,
-    },
-    call_frames::msg_asset_id,
-    context::msg_amount,
-    context::this_balance,
-    constants::DEFAULT_SUB_ID,
     string::String,
     storage::*,
-    storage::storage_api::{
-        read, 
-        write
-    },
-    storage::storage_map::*,
     hash::*,
     asset_id::*,
 };
 
-use ::events::{
-    MintEvent,
-    BurnEvent,
-    SwapEvent,
+use funi_lib::{
+    errors::PairError,
+    math::{
+        mul_gte,
+        to_u64,
+    },
+    pair::{
+        FuniSwapV2Pair,
+        PairState,
+    },
+    pool::{
+        lp_metadata,
+        Pool,
+        DECIMALS,
+        MINIMUM_LIQUIDITY,
+    },
+    src20::SRC20,
 };
 
 /// The name of a specific asset minted by this contract.
@@ -35,21 +32,27 @@ const NAME: str[5] = __to_str_array("FuSV2");
 /// The symbol of a specific asset minted by this contract.
 const SYMBOL: str[3] = __to_str_array("FV2");
 
-/// decimals
-const DECIMALS: u8 = 9;
-
-const MINIMUM_LIQUIDITY: u256 = 1000;
+/// swap fee in basis points, swap() takes it as amount_in * 3 / 1000
+const FEE_BPS: u64 = 30;
 
+// token0 and token1 have to be sorted (token0 < token1) and non-zero. A zero token
+// is only accepted with allow_base, on chains where it is the base asset. This is
+// checked by every state changing call.
 configurable {
     token0: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
     token1: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    // factory the pair is registered in, zero when deployed on its own
+    factory: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    // set for a pair of the base asset, when the base asset of the chain may be zero
+    allow_base: bool = false,
 }
 
 storage {
     /// FuniSwapV2Pair ABI
 
-    // we don't need the factory address storage, because we won't "initialize" the Pair 
-    // contract. In UniswapV2 the initialize() callable by factory sets the token0 and token1.
+    // we don't need the factory address storage, it is a configurable like token0 and token1,
+    // because we won't "initialize" the Pair contract. In UniswapV2 the initialize() callable
+    // by factory sets the token0 and token1.
 
     // reserves - deposits turned into liquidity.
     reserve0: u256 = 0,
@@ -62,155 +65,71 @@ storage {
     total_supply: u256 = 0,
 }
 
-abi FuniSwapV2Pair {
-    #[storage(read)]
-    fn get_reserves() -> (u64, u64);
-
-    #[payable]
-    #[storage(read, write)]
-    fn burn(to: Identity) -> (u64, u64);
-
-    #[storage(read, write)]
-    fn mint(to: Identity) -> u64;
-
-    #[storage(read, write)]
-    fn swap(amount0_out: u64, amount1_out: u64, to: Identity);
-}
-
 impl FuniSwapV2Pair for Contract {
     #[storage(read)]
     fn get_reserves() -> (u64, u64) {
-        let (reserve0, reserve1) = _get_reserves();
-        (_to_u64(reserve0), _to_u64(reserve1))
+        let (reserve0, reserve1) = _pool().get_reserves();
+        (to_u64(reserve0), to_u64(reserve1))
     }
 
     #[storage(read, write)]
     fn mint(to: Identity) -> u64 {
-        let total_supply = storage.total_supply.read();
-        let mut liquidity: u256 = 0;
-        let (reserve0, reserve1) = _get_reserves();
-        let balance0 = this_balance(token0).as_u256();
-        let balance1 = this_balance(token1).as_u256();
-        let amount0 = balance0 - reserve0;
-        let amount1 = balance1 - reserve1;
-
-        if total_supply == 0 {
-            liquidity = (amount0 * amount1).sqrt() - MINIMUM_LIQUIDITY;
-            storage.total_supply.write(MINIMUM_LIQUIDITY); // instead of mint 1000 to 0
-        } else {
-            liquidity = _min(
-                (amount0 * total_supply) / reserve0,
-                (amount1 * total_supply) / reserve1
-            )
-        }
-        require(liquidity > 0, "Insufficient Liquidity");
-        _mint(to, liquidity);
-        _update(balance0, balance1, reserve0, reserve1);
-
-        log(MintEvent{
-            sender: msg_sender().unwrap(),
-            to,
-            amount0,
-            amount1,
-        });
-
-        _to_u64(liquidity)
+        _pool().mint(to)
     }
 
     #[payable]
     #[storage(read, write)]
-    fn burn(to: Identity) -> (u64, u64) {
-        let total_supply = storage.total_supply.read();
-        let liquidity = msg_amount().as_u256();
-        let (reserve0, reserve1) = _get_reserves();
-        let balance0 = this_balance(token0).as_u256();
-        let balance1 = this_balance(token1).as_u256();
-
-        let amount0 = (liquidity * balance0) / total_supply;
-        let amount1 = (liquidity * balance1) / total_supply;
-        require(amount0 > 0 && amount1 > 0, "Insufficient Liquidity Burned");
-        _burn(liquidity);
-        transfer(to, token0, _to_u64(amount0));
-        transfer(to, token1, _to_u64(amount1));
-
-        _update(
-            this_balance(token0).as_u256(),
-            this_balance(token1).as_u256(),
-            reserve0,
-            reserve1
-        );
-
-        log(BurnEvent{
-            sender: msg_sender().unwrap(),
-            to,
-            amount0,
-            amount1,
-        });
-
-        (_to_u64(amount0), _to_u64(amount1))
+    fn burn(to: Identity, liquidity: Option<u64>) -> (u64, u64) {
+        _pool().burn(to, liquidity)
     }
 
     #[storage(read, write)]
     fn swap(amount0_out: u64, amount1_out: u64, to: Identity) {
-        require(amount0_out > 0 || amount1_out > 0, "Insufficient Output Amount");
-        let (reserve0, reserve1) = _get_reserves();
+        let pool = _pool();
+        let swap = pool.swap_begin(amount0_out, amount1_out, to);
+
+        let balance0_adjusted = (swap.balance0 * 1000) - (swap.amount0_in * 3);
+        let balance1_adjusted = (swap.balance1 * 1000) - (swap.amount1_in * 3);
+        // balance0_adjusted * balance1_adjusted >= reserve0 * reserve1 * 1000^2,
+        // compared on the 512-bit products
         require(
-            amount0_out.as_u256() < reserve0 && amount1_out.as_u256() < reserve1, 
-            "Insufficient Liquidity"
+            mul_gte(balance0_adjusted, balance1_adjusted, swap.reserve0 * 1000, swap.reserve1 * 1000),
+            PairError::KInvariantIncorrect
         );
-        // do we need to check if we don't transfer tokens to token0 or token1 contracts?
+        pool.swap_end(swap, amount0_out, amount1_out, to);
+    }
 
-        if amount0_out > 0 {
-            transfer(to, token0, amount0_out);
-        }
-        if amount1_out > 0 {
-            transfer(to, token1, amount1_out);
-        }
-        let balance0 = this_balance(token0).as_u256();
-        let balance1 = this_balance(token1).as_u256();
+    fn token0() -> AssetId {
+        token0
+    }
 
-        let mut amount0_in: u256 = 0;
-        let mut amount1_in: u256 = 0;
+    fn token1() -> AssetId {
+        token1
+    }
 
-        if balance0 > reserve0 - amount0_out.as_u256() {
-            amount0_in = balance0 - (reserve0 - amount0_out.as_u256());
-        }
-        if balance1 > reserve1 - amount1_out.as_u256() {
-            amount1_in = balance1 - (reserve1 - amount1_out.as_u256());
-        }
-        require(amount0_in > 0 || amount1_in > 0, "Insufficient Input Amount");
+    fn minimum_liquidity() -> u64 {
+        to_u64(MINIMUM_LIQUIDITY)
+    }
 
-        let balance0_adjusted = (balance0 * 1000) - (amount0_in * 3);
-        let balance1_adjusted = (balance1 * 1000) - (amount1_in * 3);
-        require(
-            balance0_adjusted * balance1_adjusted >= reserve0 * reserve1 * 1000000,
-            "K Invariant Incorrect"
-        );
-        _update(balance0, balance1, reserve0, reserve1);
+    fn factory() -> ContractId {
+        factory
+    }
 
-        log(SwapEvent{
-            sender: msg_sender().unwrap(),
-            to,
-            amount0_in,
-            amount1_in,
-            amount0_out,
-            amount1_out,
-        });
+    fn stable() -> bool {
+        false
     }
-}
 
-abi SRC20 {
     #[storage(read)]
-    fn total_assets() -> u64;
+    fn get_pair_state() -> PairState {
+        let pool = _pool();
+        let (reserve0, reserve1) = pool.get_reserves();
+        pool.get_pair_state(FEE_BPS, reserve0 * reserve1)
+    }
 
     #[storage(read)]
-    fn total_supply(asset: AssetId) -> Option<u64>;
-
-    fn name(asset: AssetId) -> Option<String>;
-
-    fn symbol(asset: AssetId) -> Option<String>;
-
-    fn decimals(asset: AssetId) -> Option<u8>;
+    fn get_underlying(liquidity: u64) -> (u64, u64) {
+        _pool().get_underlying(liquidity)
+    }
 }
 
 impl SRC20 for Contract {
@@ -221,80 +140,32 @@ impl SRC20 for Contract {
 
     #[storage(read)]
     fn total_supply(asset: AssetId) -> Option<u64> {
-        if asset == AssetId::default() {
-            <u64 as TryFrom<u256>>::try_from(storage.total_supply.read())
-        } else {
-            None
-        }
+        _pool().lp_total_supply(asset)
     }
 
+    #[storage(read)]
     fn name(asset: AssetId) -> Option<String> {
-        if asset == AssetId::default() {
-            Some(String::from_ascii_str(from_str_array(NAME)))
-        } else {
-            None
-        }
+        lp_metadata(asset, String::from_ascii_str(from_str_array(NAME)))
     }
 
+    #[storage(read)]
     fn symbol(asset: AssetId) -> Option<String> {
-        if asset == AssetId::default() {
-            Some(String::from_ascii_str(from_str_array(SYMBOL)))
-        } else {
-            None
-        }
+        lp_metadata(asset, String::from_ascii_str(from_str_array(SYMBOL)))
     }
 
+    #[storage(read)]
     fn decimals(asset: AssetId) -> Option<u8> {
-        if asset == AssetId::default() {
-            Some(DECIMALS)
-        } else {
-            None
-        }
+        lp_metadata(asset, DECIMALS)
     }
 }
 
-#[storage(read, write)]
-fn _mint(recipient: Identity, amount: u256) {
-    // Increment total supply of the asset and mint to the recipient.
-    storage.total_supply.write(amount + storage.total_supply.read());
-    mint_to(recipient, DEFAULT_SUB_ID, _to_u64(amount));
-}
-
-#[storage(read, write)]
-fn _burn(amount: u256) {
-    require(
-        msg_asset_id() == AssetId::default(),
-        "Incorrect asset provided",
-    );
- 
-    storage.total_supply.write(storage.total_supply.read() - amount);
-    burn(DEFAULT_SUB_ID, _to_u64(amount));
-}
-
-fn _min(a: u256, b: u256) -> u256 {
-    if a < b {
-        a
-    } else {
-        b
+fn _pool() -> Pool {
+    Pool {
+        token0,
+        token1,
+        allow_base,
+        reserve0: storage.reserve0,
+        reserve1: storage.reserve1,
+        total_supply: storage.total_supply,
     }
 }
-
-#[storage(read)]
-fn _get_reserves() -> (u256, u256) {
-    (
-        storage.reserve0.read(),
-        storage.reserve1.read()
-    )
-}
-
-#[storage(read, write)]
-fn _update(balance0: u256, balance1: u256, reserve0: u256, reserve1: u256) {
-    storage.reserve0.write(balance0);
-    storage.reserve1.write(balance1);
-}
-
-fn _to_u64(amount: u256) -> u64 {
-    require(amount <= u64::max().as_u256(), "Amount too high");
-    <u64 as TryFrom<u256>>::try_from(amount).unwrap()
-}
-
@@ -5,6 +5,7 @@ use std::{
         transfer,
     },
     call_frames::msg_asset_id,
+    context::balance_of,
     context::msg_amount,
     context::this_balance,
     constants::DEFAULT_SUB_ID,
@@ -19,12 +20,22 @@ use std::{
     asset_id::*,
 };
 
-/// Errors
-pub const INCORRECT_ASSET_ID_SIGNAL = 0xffff_ffff_ffff_0000;
+use funi_lib::{
+    errors::RouterError,
+    math::{
+        get_amount_out,
+        mul_div,
+        to_u64,
+    },
+    pair::FuniSwapV2Pair,
+    src20::TaxedTransfer,
+};
 
 configurable {
     token0: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
     token1: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    // pair of token0 and token1 used by the liquidity and swap routes
+    pair: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
 }
 
 abi FuniSwapV2Router02 {
@@ -37,6 +48,43 @@ abi FuniSwapV2Router02 {
 
     #[storage(read)]
     fn get_deposits(depositor: Identity) -> (u64, u64);
+
+    // The base asset is forwarded with the call, the other token is taken from
+    // the deposits of the sender. Returns (amount_token, amount_eth, liquidity).
+    #[payable]
+    #[storage(read, write)]
+    fn add_liquidity_eth(
+        amount_token_desired: u64,
+        amount_token_min: u64,
+        amount_eth_min: u64,
+        to: Identity,
+    ) -> (u64, u64, u64);
+
+    #[payable]
+    fn swap_exact_eth_for_tokens(amount_out_min: u64, to: Identity) -> u64;
+
+    #[payable]
+    fn swap_exact_tokens_for_eth(amount_out_min: u64, to: Identity) -> u64;
+
+    // Swaps part of the forwarded token for the other one so that both match the
+    // reserve ratio, then mints LP tokens to `to`. Returns the liquidity.
+    #[payable]
+    fn zap_in(min_lp_out: u64, to: Identity) -> u64;
+
+    // Burns the forwarded LP tokens and swaps the other token into asset_out.
+    // Returns the total amount of asset_out sent to `to`.
+    #[payable]
+    fn zap_out(asset_out: AssetId, min_amount_out: u64, to: Identity) -> u64;
+
+    // Sends the coins forwarded with the call to the pair through the transfer() of
+    // their token contract, which takes its fee, and swaps what the pair's balance
+    // grew by. The coins have to be the default asset of `token`.
+    #[payable]
+    fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        token: ContractId,
+        amount_out_min: u64,
+        to: Identity,
+    ) -> u64;
 }
 
 impl FuniSwapV2Router02 for Contract {
@@ -44,14 +92,13 @@ impl FuniSwapV2Router02 for Contract {
     #[storage(read, write)]
     fn deposit(to: Identity) {
         let asset_id = msg_asset_id();
+        require(asset_id == token0 || asset_id == token1, RouterError::IncorrectAssetId);
         let (token0_deposit, token1_deposit) = _get_deposits(to);
 
         if asset_id == token0 {
             _update_deposits(to, Some(msg_amount() + token0_deposit), None);
-        } else if asset_id == token1 {
-            _update_deposits(to, None, Some(msg_amount() + token1_deposit));
         } else {
-            revert(INCORRECT_ASSET_ID_SIGNAL);
+            _update_deposits(to, None, Some(msg_amount() + token1_deposit));
         }
     }
 
@@ -59,7 +106,7 @@ impl FuniSwapV2Router02 for Contract {
     fn withdraw(to: Identity) {
         let sender = msg_sender().unwrap();
         let (token0_deposit, token1_deposit) = _get_deposits(sender);
-        require(token0_deposit > 0 || token1_deposit > 0, "No deposits");
+        require(token0_deposit > 0 || token1_deposit > 0, RouterError::NoDeposits);
         if token0_deposit > 0 {
             _update_deposits(sender, Some(0), None);
             transfer(to, token0, token0_deposit);
@@ -74,6 +121,229 @@ impl FuniSwapV2Router02 for Contract {
     fn get_deposits(depositor: Identity) -> (u64, u64) {
         _get_deposits(depositor)
     }
+
+    #[payable]
+    #[storage(read, write)]
+    fn add_liquidity_eth(
+        amount_token_desired: u64,
+        amount_token_min: u64,
+        amount_eth_min: u64,
+        to: Identity,
+    ) -> (u64, u64, u64) {
+        let eth = AssetId::base();
+        require(msg_asset_id() == eth, RouterError::BaseAssetExpected);
+        let token = _other_token(eth);
+        let amount_eth_desired = msg_amount();
+        let sender = msg_sender().unwrap();
+        let deposit = _get_deposit(sender, token);
+        require(deposit >= amount_token_desired, RouterError::InsufficientDeposit);
+
+        let (reserve_token, reserve_eth) = _get_reserves_for(token);
+        let (amount_token, amount_eth) = _add_liquidity_amounts(
+            amount_token_desired,
+            amount_eth_desired,
+            amount_token_min,
+            amount_eth_min,
+            reserve_token,
+            reserve_eth,
+        );
+
+        // the unused part of the deposit stays in the ledger
+        _set_deposit(sender, token, deposit - amount_token);
+        transfer(Identity::ContractId(pair), token, amount_token);
+        transfer(Identity::ContractId(pair), eth, amount_eth);
+        if amount_eth_desired > amount_eth {
+            transfer(sender, eth, amount_eth_desired - amount_eth);
+        }
+        let liquidity = abi(FuniSwapV2Pair, pair.bits()).mint(to);
+        (amount_token, amount_eth, liquidity)
+    }
+
+    #[payable]
+    fn swap_exact_eth_for_tokens(amount_out_min: u64, to: Identity) -> u64 {
+        let eth = AssetId::base();
+        require(msg_asset_id() == eth, RouterError::BaseAssetExpected);
+        let token = _other_token(eth);
+        _swap_exact_in(eth, token, msg_amount(), amount_out_min, to)
+    }
+
+    #[payable]
+    fn swap_exact_tokens_for_eth(amount_out_min: u64, to: Identity) -> u64 {
+        let eth = AssetId::base();
+        let token = _other_token(eth);
+        require(msg_asset_id() == token, RouterError::IncorrectAsset);
+        _swap_exact_in(token, eth, msg_amount(), amount_out_min, to)
+    }
+
+    #[payable]
+    fn zap_in(min_lp_out: u64, to: Identity) -> u64 {
+        let asset_in = msg_asset_id();
+        let asset_out = _other_token(asset_in);
+        let amount_in = msg_amount();
+        let (reserve_in, _reserve_out) = _get_reserves_for(asset_in);
+        let swap_in = _zap_swap_amount(amount_in, reserve_in);
+
+        // the swap output comes back to the router and goes to the pair with the rest
+        let router = Identity::ContractId(ContractId::this());
+        let swap_out = _swap_exact_in(asset_in, asset_out, swap_in, 0, router);
+        transfer(Identity::ContractId(pair), asset_in, amount_in - swap_in);
+        transfer(Identity::ContractId(pair), asset_out, swap_out);
+
+        let liquidity = abi(FuniSwapV2Pair, pair.bits()).mint(to);
+        require(liquidity >= min_lp_out, RouterError::InsufficientLpOutput);
+        liquidity
+    }
+
+    #[payable]
+    fn zap_out(asset_out: AssetId, min_amount_out: u64, to: Identity) -> u64 {
+        let lp_asset = AssetId::new(pair, DEFAULT_SUB_ID);
+        require(msg_asset_id() == lp_asset, RouterError::IncorrectAsset);
+        let asset_in = _other_token(asset_out);
+
+        let funi_pair = abi(FuniSwapV2Pair, pair.bits());
+        let router = Identity::ContractId(ContractId::this());
+        let (amount0, amount1) = funi_pair.burn {
+            asset_id: lp_asset.bits(),
+            coins: msg_amount(),
+        }(router, None);
+        let (amount_out, amount_in) = if asset_out == funi_pair.token0() {
+            (amount0, amount1)
+        } else {
+            (amount1, amount0)
+        };
+
+        let swap_out = _swap_exact_in(asset_in, asset_out, amount_in, 0, to);
+        let total_out = amount_out + swap_out;
+        require(total_out >= min_amount_out, RouterError::InsufficientOutputAmount);
+        transfer(to, asset_out, amount_out);
+        total_out
+    }
+
+    #[payable]
+    fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        token: ContractId,
+        amount_out_min: u64,
+        to: Identity,
+    ) -> u64 {
+        let asset_in = msg_asset_id();
+        require(asset_in == AssetId::new(token, DEFAULT_SUB_ID), RouterError::IncorrectAsset);
+        require(msg_amount() > 0, RouterError::InsufficientInputAmount);
+        let asset_out = _other_token(asset_in);
+        let (reserve_in, reserve_out) = _get_reserves_for(asset_in);
+        // measured like UniswapV2Router02._swapSupportingFeeOnTransferTokens: the token
+        // takes its fee in transfer(), and only the balance change of this transfer
+        // counts, coins already sitting in the pair are not the caller's. The amount
+        // returned by the token is not trusted.
+        let balance_before = balance_of(pair, asset_in);
+        let taxed = abi(TaxedTransfer, token.bits());
+        let _ = taxed.transfer {
+            asset_id: asset_in.bits(),
+            coins: msg_amount(),
+        }(Identity::ContractId(pair));
+        let balance_after = balance_of(pair, asset_in);
+        require(balance_after > balance_before, RouterError::InsufficientInputAmount);
+        let amount_in = balance_after - balance_before;
+        let amount_out = _get_amount_out(amount_in, reserve_in, reserve_out);
+        require(amount_out >= amount_out_min, RouterError::InsufficientOutputAmount);
+        _swap_out(asset_out, amount_out, to);
+        amount_out
+    }
+}
+
+// the token paired with asset, which has to be token0 or token1
+fn _other_token(asset: AssetId) -> AssetId {
+    require(asset == token0 || asset == token1, RouterError::IncorrectAssetId);
+    if asset == token0 {
+        token1
+    } else {
+        token0
+    }
+}
+
+// reserves of the pair as (reserve of asset, reserve of the other token)
+fn _get_reserves_for(asset: AssetId) -> (u64, u64) {
+    let funi_pair = abi(FuniSwapV2Pair, pair.bits());
+    let (reserve0, reserve1) = funi_pair.get_reserves();
+    if asset == funi_pair.token0() {
+        (reserve0, reserve1)
+    } else {
+        (reserve1, reserve0)
+    }
+}
+
+// amounts added to the pair, keeping the current price (UniswapV2Router02._addLiquidity)
+fn _add_liquidity_amounts(
+    amount_a_desired: u64,
+    amount_b_desired: u64,
+    amount_a_min: u64,
+    amount_b_min: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+) -> (u64, u64) {
+    if reserve_a == 0 && reserve_b == 0 {
+        return (amount_a_desired, amount_b_desired);
+    }
+    let amount_b_optimal = _quote(amount_a_desired, reserve_a, reserve_b);
+    if amount_b_optimal <= amount_b_desired {
+        require(amount_b_optimal >= amount_b_min, RouterError::InsufficientBAmount);
+        (amount_a_desired, amount_b_optimal)
+    } else {
+        let amount_a_optimal = _quote(amount_b_desired, reserve_b, reserve_a);
+        require(amount_a_optimal <= amount_a_desired, RouterError::InsufficientAAmount);
+        require(amount_a_optimal >= amount_a_min, RouterError::InsufficientAAmount);
+        (amount_a_optimal, amount_b_desired)
+    }
+}
+
+fn _quote(amount_a: u64, reserve_a: u64, reserve_b: u64) -> u64 {
+    require(amount_a > 0, RouterError::InsufficientAmount);
+    require(reserve_a > 0 && reserve_b > 0, RouterError::InsufficientLiquidity);
+    to_u64(mul_div(amount_a.as_u256(), reserve_b.as_u256(), reserve_a.as_u256()))
+}
+
+// part of amount_in to swap so that the rest and the swap output match the reserves
+// after the swap, with the 0.3% fee:
+// (sqrt(reserve_in * (reserve_in * 3988009 + amount_in * 3988000)) - reserve_in * 1997) / 1994
+fn _zap_swap_amount(amount_in: u64, reserve_in: u64) -> u64 {
+    require(reserve_in > 0, RouterError::InsufficientLiquidity);
+    let amount_in = amount_in.as_u256();
+    let reserve_in = reserve_in.as_u256();
+    let root = (reserve_in * (reserve_in * 3988009 + amount_in * 3988000)).sqrt();
+    to_u64((root - reserve_in * 1997) / 1994)
+}
+
+// get_amount_out() of funi_lib, reverting with the router errors on an empty input or pair
+fn _get_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    require(amount_in > 0, RouterError::InsufficientInputAmount);
+    require(reserve_in > 0 && reserve_out > 0, RouterError::InsufficientLiquidity);
+    get_amount_out(amount_in, reserve_in, reserve_out)
+}
+
+// send amount_in of asset_in, held by the router, to the pair and swap it for asset_out
+fn _swap_exact_in(
+    asset_in: AssetId,
+    asset_out: AssetId,
+    amount_in: u64,
+    amount_out_min: u64,
+    to: Identity,
+) -> u64 {
+    let (reserve_in, reserve_out) = _get_reserves_for(asset_in);
+    let amount_out = _get_amount_out(amount_in, reserve_in, reserve_out);
+    require(amount_out >= amount_out_min, RouterError::InsufficientOutputAmount);
+
+    transfer(Identity::ContractId(pair), asset_in, amount_in);
+    _swap_out(asset_out, amount_out, to);
+    amount_out
+}
+
+// swap the input already held by the pair for amount_out of asset_out
+fn _swap_out(asset_out: AssetId, amount_out: u64, to: Identity) {
+    let funi_pair = abi(FuniSwapV2Pair, pair.bits());
+    if asset_out == funi_pair.token0() {
+        funi_pair.swap(amount_out, 0, to);
+    } else {
+        funi_pair.swap(0, amount_out, to);
+    }
 }
 
 #[storage(read)]
@@ -85,6 +355,21 @@ fn _get_deposits(depositor: Identity) -> (u64, u64) {
     (token0_existing_deposit, token1_existing_deposit)
 }
 
+#[storage(read)]
+fn _get_deposit(depositor: Identity, asset: AssetId) -> u64 {
+    let (token0_deposit, token1_deposit) = _get_deposits(depositor);
+    if asset == token0 { token0_deposit } else { token1_deposit }
+}
+
+#[storage(read, write)]
+fn _set_deposit(depositor: Identity, asset: AssetId, amount: u64) {
+    if asset == token0 {
+        _update_deposits(depositor, Some(amount), None);
+    } else {
+        _update_deposits(depositor, None, Some(amount));
+    }
+}
+
 #[storage(read, write)]
 fn _update_deposits(depositor: Identity, amount0: Option<u64>, amount1: Option<u64>) {
     if amount0 != None {
@@ -0,0 +1,306 @@
+contract;
+
+// Stable swap variant of the FuniSwapV2Pair for pegged assets. It keeps the
+// FuniSwapV2Pair ABI, only the swap invariant differs: instead of x * y = k
+// it uses the Solidly x^3 * y + y^3 * x = k curve, computed on the reserves
+// normalized to the same number of decimals.
+
+use std::{
+    string::String,
+    storage::*,
+    hash::*,
+    asset_id::*,
+    context::this_balance,
+};
+
+use funi_lib::{
+    errors::{
+        MathError,
+        PairError,
+    },
+    math::to_u64,
+    pair::{
+        FuniSwapV2Pair,
+        PairState,
+    },
+    pool::{
+        lp_metadata,
+        Pool,
+        DECIMALS,
+        MINIMUM_LIQUIDITY,
+    },
+    src20::SRC20,
+};
+
+/// The name of a specific asset minted by this contract.
+const NAME: str[6] = __to_str_array("FuSV2S");
+
+/// The symbol of a specific asset minted by this contract.
+const SYMBOL: str[4] = __to_str_array("FV2S");
+
+/// Precision the amounts are normalized to before computing the invariant, 18
+/// decimals like the Solidly / Velodrome pools. With less, the products of small
+/// reserves round down to a zero invariant, which any swap satisfies.
+const ONE: u256 = 1_000_000_000_000_000_000;
+
+/// Invariant the first deposit has to be above, so that the pool never holds dust
+/// reserves whose invariant rounds down to zero.
+const MINIMUM_K: u256 = 10_000_000_000;
+
+/// Swap fee in basis points, lower than the 0.3% of the volatile pair.
+const FEE_BPS: u64 = 5;
+
+// token0 and token1 have to be sorted (token0 < token1) and non-zero. A zero token
+// is only accepted with allow_base, on chains where it is the base asset, and their
+// decimals have to be set. This is checked by every state changing call.
+configurable {
+    token0: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    token1: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    // factory the pair is registered in, zero when deployed on its own
+    factory: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    // set for a pair of the base asset, when the base asset of the chain may be zero
+    allow_base: bool = false,
+    // SRC20 decimals of token0 and token1, set from the tokens by the SDK. There is
+    // no default: zero is unset and checked like the tokens.
+    decimals0: u8 = 0,
+    decimals1: u8 = 0,
+}
+
+storage {
+    /// FuniSwapV2Pair ABI
+
+    // we don't need the factory address storage, it is a configurable like token0 and token1,
+    // because we won't "initialize" the Pair contract. In UniswapV2 the initialize() callable
+    // by factory sets the token0 and token1.
+
+    // reserves - deposits turned into liquidity.
+    reserve0: u256 = 0,
+    reserve1: u256 = 0,
+
+    /// SRC20 ABI
+    // The total number of distinguishable assets minted by this contract.
+    total_assets: u64 = 1,
+    // The total supply of coins for a specific asset minted by this contract.
+    total_supply: u256 = 0,
+}
+
+impl FuniSwapV2Pair for Contract {
+    #[storage(read)]
+    fn get_reserves() -> (u64, u64) {
+        let (reserve0, reserve1) = _pool().get_reserves();
+        (to_u64(reserve0), to_u64(reserve1))
+    }
+
+    #[storage(read, write)]
+    fn mint(to: Identity) -> u64 {
+        _check_decimals();
+        let pool = _pool();
+        if storage.total_supply.read() == 0 {
+            pool.check_tokens();
+            let (reserve0, reserve1) = pool.get_reserves();
+            let amount0 = this_balance(token0).as_u256() - reserve0;
+            let amount1 = this_balance(token1).as_u256() - reserve1;
+            require(_k(amount0, amount1) > MINIMUM_K, PairError::InsufficientK);
+        }
+        pool.mint(to)
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn(to: Identity, liquidity: Option<u64>) -> (u64, u64) {
+        _check_decimals();
+        _pool().burn(to, liquidity)
+    }
+
+    #[storage(read, write)]
+    fn swap(amount0_out: u64, amount1_out: u64, to: Identity) {
+        _check_decimals();
+        let pool = _pool();
+        let swap = pool.swap_begin(amount0_out, amount1_out, to);
+
+        let balance0_adjusted = swap.balance0 - (swap.amount0_in * FEE_BPS.as_u256()) / 10000;
+        let balance1_adjusted = swap.balance1 - (swap.amount1_in * FEE_BPS.as_u256()) / 10000;
+        require(
+            _k(balance0_adjusted, balance1_adjusted) >= _k(swap.reserve0, swap.reserve1),
+            PairError::KInvariantIncorrect
+        );
+        pool.swap_end(swap, amount0_out, amount1_out, to);
+    }
+
+    fn token0() -> AssetId {
+        token0
+    }
+
+    fn token1() -> AssetId {
+        token1
+    }
+
+    fn minimum_liquidity() -> u64 {
+        to_u64(MINIMUM_LIQUIDITY)
+    }
+
+    fn factory() -> ContractId {
+        factory
+    }
+
+    fn stable() -> bool {
+        true
+    }
+
+    #[storage(read)]
+    fn get_pair_state() -> PairState {
+        let pool = _pool();
+        let (reserve0, reserve1) = pool.get_reserves();
+        pool.get_pair_state(FEE_BPS, _k(reserve0, reserve1))
+    }
+
+    #[storage(read)]
+    fn get_underlying(liquidity: u64) -> (u64, u64) {
+        _pool().get_underlying(liquidity)
+    }
+}
+
+abi FuniSwapV2StablePair {
+    #[storage(read)]
+    fn get_amount_out(amount_in: u64, asset_in: AssetId) -> u64;
+}
+
+impl FuniSwapV2StablePair for Contract {
+    #[storage(read)]
+    fn get_amount_out(amount_in: u64, asset_in: AssetId) -> u64 {
+        require(asset_in == token0 || asset_in == token1, PairError::InvalidAsset);
+        to_u64(_get_amount_out(amount_in.as_u256(), asset_in))
+    }
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        storage.total_assets.read()
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        _pool().lp_total_supply(asset)
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        lp_metadata(asset, String::from_ascii_str(from_str_array(NAME)))
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        lp_metadata(asset, String::from_ascii_str(from_str_array(SYMBOL)))
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        lp_metadata(asset, DECIMALS)
+    }
+}
+
+fn _pool() -> Pool {
+    Pool {
+        token0,
+        token1,
+        allow_base,
+        reserve0: storage.reserve0,
+        reserve1: storage.reserve1,
+        total_supply: storage.total_supply,
+    }
+}
+
+fn _check_decimals() {
+    require(decimals0 > 0 && decimals1 > 0, PairError::UnsetDecimals);
+}
+
+/*
+ * ---- Stable invariant, same as the Solidly / Velodrome stable pools
+ */
+
+fn _scale(decimals: u8) -> u256 {
+    10.as_u256().pow(decimals.as_u32())
+}
+
+fn _normalize(amount: u256, decimals: u8) -> u256 {
+    amount * ONE / _scale(decimals)
+}
+
+// x^3 * y + y^3 * x on the normalized reserves
+fn _k(x: u256, y: u256) -> u256 {
+    let x = _normalize(x, decimals0);
+    let y = _normalize(y, decimals1);
+    let a = (x * y) / ONE;
+    let b = (x * x) / ONE + (y * y) / ONE;
+    (a * b) / ONE
+}
+
+fn _f(x0: u256, y: u256) -> u256 {
+    x0 * (y * y / ONE * y / ONE) / ONE + (x0 * x0 / ONE * x0 / ONE) * y / ONE
+}
+
+// derivative of _f() in y
+fn _d(x0: u256, y: u256) -> u256 {
+    3 * x0 * (y * y / ONE) / ONE + (x0 * x0 / ONE * x0 / ONE)
+}
+
+// Newton's method: y such that _f(x0, y) = xy, starting from the current reserve.
+// Guarded like the Velodrome pools: a step rounded to zero moves by one unit. A step
+// down to zero or below, or no convergence in 255 steps, reverts: a zero y would
+// quote the whole reserve.
+fn _get_y(x0: u256, xy: u256, y: u256) -> u256 {
+    let mut y = y;
+    let mut i = 0;
+    while i < 255 {
+        let k = _f(x0, y);
+        let d = _d(x0, y);
+        require(d > 0, MathError::DivisionByZero);
+        if k < xy {
+            let mut dy = ((xy - k) * ONE) / d;
+            if dy == 0 {
+                if _f(x0, y + 1) > xy {
+                    return y + 1;
+                }
+                dy = 1;
+            }
+            y = y + dy;
+        } else {
+            let mut dy = ((k - xy) * ONE) / d;
+            if dy == 0 {
+                if k == xy || y == 0 || _f(x0, y - 1) < xy {
+                    return y;
+                }
+                dy = 1;
+            }
+            require(dy < y, PairError::CurveNotConverged);
+            y = y - dy;
+        }
+        i += 1;
+    }
+    require(false, PairError::CurveNotConverged);
+    y
+}
+
+// output for amount_in after the fee, rounded down by one unit in favour of the pool
+#[storage(read)]
+fn _get_amount_out(amount_in: u256, asset_in: AssetId) -> u256 {
+    let (reserve0, reserve1) = _pool().get_reserves();
+    let amount_in = amount_in - (amount_in * FEE_BPS.as_u256()) / 10000;
+    let xy = _k(reserve0, reserve1);
+    let (amount_in, reserve_in, reserve_out, decimals_out) = if asset_in == token0 {
+        (_normalize(amount_in, decimals0), _normalize(reserve0, decimals0), _normalize(reserve1, decimals1), decimals1)
+    } else {
+        (_normalize(amount_in, decimals1), _normalize(reserve1, decimals1), _normalize(reserve0, decimals0), decimals0)
+    };
+    let y = _get_y(amount_in + reserve_in, xy, reserve_out);
+    if y >= reserve_out {
+        return 0;
+    }
+    let amount_out = (reserve_out - y) * _scale(decimals_out) / ONE;
+    if amount_out > 0 {
+        amount_out - 1
+    } else {
+        0
+    }
+}
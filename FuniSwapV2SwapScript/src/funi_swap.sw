@@ -0,0 +1,44 @@
+script;
+
+use std::asset::transfer;
+
+use funi_lib::{
+    errors::RouterError,
+    math::get_amount_out,
+    pair::FuniSwapV2Pair,
+};
+
+// Transfers amount_in of asset_in from the transaction inputs to the pair and swaps
+// it for the other token, sent to `to`. The output is computed from the reserves at
+// execution time, after the 0.3% fee, and the script reverts if it is below
+// amount_out_min. Returns the output amount.
+fn main(
+    pair: ContractId,
+    asset_in: AssetId,
+    amount_in: u64,
+    amount_out_min: u64,
+    to: Identity,
+) -> u64 {
+    let funi_pair = abi(FuniSwapV2Pair, pair.bits());
+    let (reserve0, reserve1) = funi_pair.get_reserves();
+    let token0 = funi_pair.token0();
+    require(asset_in == token0 || asset_in == funi_pair.token1(), RouterError::InvalidAsset);
+
+    let (reserve_in, reserve_out) = if asset_in == token0 {
+        (reserve0, reserve1)
+    } else {
+        (reserve1, reserve0)
+    };
+    require(amount_in > 0, RouterError::InsufficientInputAmount);
+    require(reserve_in > 0 && reserve_out > 0, RouterError::InsufficientLiquidity);
+    let amount_out = get_amount_out(amount_in, reserve_in, reserve_out);
+    require(amount_out >= amount_out_min, RouterError::InsufficientOutputAmount);
+
+    transfer(Identity::ContractId(pair), asset_in, amount_in);
+    if asset_in == token0 {
+        funi_pair.swap(0, amount_out, to);
+    } else {
+        funi_pair.swap(amount_out, 0, to);
+    }
+    amount_out
+}
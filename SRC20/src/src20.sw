@@ -22,6 +22,8 @@ use std::{
 
 use std::{bytes::Bytes, bytes_conversions::{b256::*, u16::*, u256::*, u32::*, u64::*,}};
 
+use funi_lib::src20::SRC20;
+
 configurable {
     /// The name of a specific asset minted by this contract.
     name: str[5] = __to_str_array("Token"),
@@ -32,22 +34,8 @@ configurable {
 }
 
 
-abi SRC20 {
-    #[storage(read)]
-    fn total_assets() -> u64;
-
-    #[storage(read)]
-    fn total_supply(asset: AssetId) -> Option<u64>;
-
-    #[storage(read)]
-    fn name(asset: AssetId) -> Option<String>;
-
-    #[storage(read)]
-    fn symbol(asset: AssetId) -> Option<String>;
-
-    #[storage(read)]
-    fn decimals(asset: AssetId) -> Option<u8>;
-
+// the SRC20 getters come from funi_lib, minting and burning are specific to this token
+abi SRC20Mintable {
     #[storage(read, write)]
     fn mint(recipient: Identity, sub_id: SubId, amount: u64);
 
@@ -105,7 +93,9 @@ impl SRC20 for Contract {
             None
         }
     }
+}
 
+impl SRC20Mintable for Contract {
     #[storage(read, write)]
     fn mint(recipient: Identity, sub_id: SubId, amount: u64) {
         require(sub_id == DEFAULT_SUB_ID, "Incorrect Sub Id");
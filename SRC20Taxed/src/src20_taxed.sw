@@ -0,0 +1,111 @@
+contract;
+
+// Test token misbehaving like fee-on-transfer and rebasing tokens do on other chains.
+//
+// Native asset transfers on Fuel can't be taxed and a contract can't touch the coins
+// held by another contract, so both behaviours go through this contract:
+// - transfer() forwards the coins minus a fee of fee_bps, which is burnt.
+// - rebase() mints rebase_bps of the balance a contract holds to that contract.
+//   Balances can only grow this way, a negative rebase is not possible.
+
+use std::{
+    asset::{
+        burn,
+        mint_to,
+        transfer,
+    },
+    call_frames::msg_asset_id,
+    context::{
+        balance_of,
+        msg_amount,
+    },
+    constants::DEFAULT_SUB_ID,
+    storage::*,
+    asset_id::*,
+};
+
+configurable {
+    /// The decimals of the asset minted by this contract.
+    decimals: u8 = 9,
+    /// Fee taken by transfer(), in basis points.
+    fee_bps: u64 = 100,
+    /// Balance increase applied by rebase(), in basis points.
+    rebase_bps: u64 = 100,
+}
+
+abi SRC20Taxed {
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64>;
+
+    fn decimals(asset: AssetId) -> Option<u8>;
+
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: SubId, amount: u64);
+
+    #[payable]
+    #[storage(read, write)]
+    fn transfer(to: Identity) -> u64;
+
+    #[storage(read, write)]
+    fn rebase(holder: ContractId) -> u64;
+}
+
+storage {
+    /// The total supply of coins of the asset minted by this contract.
+    total_supply: u64 = 0,
+}
+
+impl SRC20Taxed for Contract {
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if asset == AssetId::default() {
+            Some(storage.total_supply.read())
+        } else {
+            None
+        }
+    }
+
+    fn decimals(asset: AssetId) -> Option<u8> {
+        if asset == AssetId::default() {
+            Some(decimals)
+        } else {
+            None
+        }
+    }
+
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: SubId, amount: u64) {
+        require(sub_id == DEFAULT_SUB_ID, "Incorrect Sub Id");
+
+        storage.total_supply.write(amount + storage.total_supply.read());
+        mint_to(recipient, DEFAULT_SUB_ID, amount);
+    }
+
+    // Forwards the coins sent with the call to `to`, minus the fee.
+    // Returns the amount `to` received.
+    #[payable]
+    #[storage(read, write)]
+    fn transfer(to: Identity) -> u64 {
+        require(
+            msg_asset_id() == AssetId::default(),
+            "Incorrect asset provided",
+        );
+        let amount = msg_amount();
+        let fee = amount * fee_bps / 10000;
+
+        storage.total_supply.write(storage.total_supply.read() - fee);
+        burn(DEFAULT_SUB_ID, fee);
+        transfer(to, AssetId::default(), amount - fee);
+        amount - fee
+    }
+
+    // Grows the balance of holder by rebase_bps. Returns the amount minted.
+    #[storage(read, write)]
+    fn rebase(holder: ContractId) -> u64 {
+        let amount = balance_of(holder, AssetId::default()) * rebase_bps / 10000;
+
+        storage.total_supply.write(amount + storage.total_supply.read());
+        mint_to(Identity::ContractId(holder), DEFAULT_SUB_ID, amount);
+        amount
+    }
+}
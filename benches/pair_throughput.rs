@@ -0,0 +1,245 @@
+/*
+  Throughput benchmark of the pair against an in-process node.
+
+  Deploys two tokens and a pair, then runs mints, swaps and burns one
+  after the other and reports latency percentiles, the sequential call
+  rate (1 / mean latency) and gas per operation. The calls are not
+  concurrent, so the rate is not the throughput of the node. The number
+  of operations of each kind is taken from FUNI_BENCH_OPS (default 1000).
+
+      cargo bench --bench pair_throughput
+ */
+
+use std::time::{Duration, Instant};
+
+use fuels::{
+    prelude::*,
+    types::Identity,
+};
+
+use funi_sdk::{
+    GenericMethods,
+    Pair,
+    Token,
+    TxConfig,
+    WalletTransfer,
+};
+use funi_sdk::gas::GasReport;
+use funi_sdk::instance::CallLog;
+use funi_sdk::pair::{
+    create_funi_pair_configurables,
+    get_amount_out,
+};
+use funi_sdk::utils::{
+    random_salt,
+    DEFAULT_SUB_ID,
+};
+
+const DEFAULT_OPS: usize = 1000;
+
+/* large enough for thousands of operations without running out */
+const COINS_PER_WALLET: u64 = 16;
+const COIN_AMOUNT: u64 = 1_000_000_000_000;
+const TOKEN_SUPPLY: u64 = u64::MAX / 4;
+
+const MINT_AMOUNT0: u64 = 10_000;
+const MINT_AMOUNT1: u64 = 100_000;
+const SWAP_AMOUNT_IN: u64 = 1_000;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let ops = std::env::var("FUNI_BENCH_OPS")
+        .ok()
+        .and_then(|ops| ops.parse().ok())
+        .unwrap_or(DEFAULT_OPS);
+
+    let bench = PairBench::new().await;
+    bench.seed_liquidity().await;
+
+    let results = vec![
+        ("mint", bench.run_mints(ops).await),
+        ("swap", bench.run_swaps(ops).await),
+        ("burn", bench.run_burns(ops).await),
+    ];
+
+    let report = GasReport::from_records(&bench.call_log.records());
+    println!(
+        "{:<6} {:>6} {:>10} {:>10} {:>10} {:>10} {:>10}",
+        "op", "calls", "1/mean", "p50 ms", "p90 ms", "p99 ms", "mean gas"
+    );
+    for (method, latencies) in &results {
+        let key = format!("FuniSwapV2Pair::{}", method);
+        let mean_gas = report.methods.get(&key).map(|stats| stats.mean).unwrap_or(0);
+        let stats = LatencyStats::new(latencies);
+        println!(
+            "{:<6} {:>6} {:>10.1} {:>10.2} {:>10.2} {:>10.2} {:>10}",
+            method,
+            latencies.len(),
+            stats.rate,
+            millis(stats.p50),
+            millis(stats.p90),
+            millis(stats.p99),
+            mean_gas
+        );
+    }
+}
+
+/*
+    ---- Bench state
+*/
+
+struct PairBench {
+    token0: Token,
+    token1: Token,
+    pair: Pair,
+    to: Identity,
+    call_log: CallLog,
+}
+
+impl PairBench {
+    async fn new() -> Self {
+        let wallet = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(Some(1), Some(COINS_PER_WALLET), Some(COIN_AMOUNT)),
+            None,
+            None
+        ).await.unwrap().pop().unwrap();
+        let to = Identity::Address(Address::from(wallet.address()));
+        let tx_config = TxConfig::default();
+        let call_log = CallLog::default();
+
+        let (token0, token1) = Token::deploy_sorted(wallet.clone(), tx_config).await.unwrap();
+        let token0 = token0.with_call_log(call_log.clone());
+        let token1 = token1.with_call_log(call_log.clone());
+        let funi_pair_configurables = create_funi_pair_configurables(
+            token0.get_default_asset_id(),
+            token1.get_default_asset_id()
+        ).unwrap();
+        let pair = Pair::deploy_with_configurables(wallet, random_salt(), funi_pair_configurables, tx_config).await.unwrap()
+            .with_call_log(call_log.clone());
+
+        /* mint the whole supply once, operations only transfer */
+        token0.clone().call_mint(to, DEFAULT_SUB_ID, TOKEN_SUPPLY).await.unwrap();
+        token1.clone().call_mint(to, DEFAULT_SUB_ID, TOKEN_SUPPLY).await.unwrap();
+
+        Self {
+            token0,
+            token1,
+            pair,
+            to,
+            call_log,
+        }
+    }
+
+    async fn deposit(&self, token: &Token, amount: u64) {
+        self.pair.deployer_wallet().transfer_to_contract(
+            self.pair.contract_id(),
+            token.get_default_asset_id(),
+            amount,
+            self.pair.tx_config
+        ).await.unwrap();
+    }
+
+    /*
+        Initial liquidity, so that swaps and burns have reserves to work with
+    */
+    async fn seed_liquidity(&self) {
+        self.deposit(&self.token0, MINT_AMOUNT0 * 100).await;
+        self.deposit(&self.token1, MINT_AMOUNT1 * 100).await;
+        self.pair.clone().call_mint(self.to).await.unwrap();
+    }
+
+    /*
+        Only the pair call is timed, the deposits are not.
+    */
+    async fn run_mints(&self, ops: usize) -> Vec<Duration> {
+        let mut latencies = Vec::with_capacity(ops);
+        for _ in 0..ops {
+            self.deposit(&self.token0, MINT_AMOUNT0).await;
+            self.deposit(&self.token1, MINT_AMOUNT1).await;
+            let start = Instant::now();
+            self.pair.clone().call_mint(self.to).await.unwrap();
+            latencies.push(start.elapsed());
+        }
+        latencies
+    }
+
+    async fn run_swaps(&self, ops: usize) -> Vec<Duration> {
+        let mut latencies = Vec::with_capacity(ops);
+        for i in 0..ops {
+            let zero_for_one = i % 2 == 0;
+            let (reserve0, reserve1) = self.pair.clone().call_get_reserves().await.unwrap();
+            let (amount0_out, amount1_out) = if zero_for_one {
+                self.deposit(&self.token0, SWAP_AMOUNT_IN).await;
+                (0, get_amount_out(SWAP_AMOUNT_IN, reserve0, reserve1).unwrap())
+            } else {
+                self.deposit(&self.token1, SWAP_AMOUNT_IN).await;
+                (get_amount_out(SWAP_AMOUNT_IN, reserve1, reserve0).unwrap(), 0)
+            };
+            let start = Instant::now();
+            self.pair.clone().call_swap(amount0_out, amount1_out, self.to).await.unwrap();
+            latencies.push(start.elapsed());
+        }
+        latencies
+    }
+
+    /*
+        Burns what the mints added, in equal parts
+    */
+    async fn run_burns(&self, ops: usize) -> Vec<Duration> {
+        let lp_balance = self.pair.deployer_balance(self.pair.get_default_asset_id()).await.unwrap();
+        let liquidity = (lp_balance / (ops as u64 + 1)).max(1);
+        let mut latencies = Vec::with_capacity(ops);
+        for _ in 0..ops {
+            let start = Instant::now();
+            self.pair.clone().call_burn(self.to, liquidity).await.unwrap();
+            latencies.push(start.elapsed());
+        }
+        latencies
+    }
+}
+
+/*
+    ---- Statistics
+*/
+
+struct LatencyStats {
+    /* calls per second one after the other, 1 / mean latency */
+    rate: f64,
+    p50: Duration,
+    p90: Duration,
+    p99: Duration,
+}
+
+impl LatencyStats {
+    fn new(latencies: &[Duration]) -> Self {
+        let mut sorted = latencies.to_vec();
+        sorted.sort();
+        let total: Duration = sorted.iter().sum();
+        let rate = if total.is_zero() {
+            0.0
+        } else {
+            sorted.len() as f64 / total.as_secs_f64()
+        };
+        Self {
+            rate,
+            p50: percentile(&sorted, 50),
+            p90: percentile(&sorted, 90),
+            p99: percentile(&sorted, 99),
+        }
+    }
+}
+
+/*
+    Nearest-rank percentile of sorted latencies
+*/
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
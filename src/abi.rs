@@ -0,0 +1,108 @@
+use fuels::{
+    prelude::*,
+    core::codec::LogDecoder,
+    types::ContractId,
+};
+
+// Load abi from json
+abigen!(
+    Contract(
+        name = "SRC20",
+        abi = "./SRC20/out/debug/src20-abi.json"
+    ),
+    Contract(
+        name = "SRC20Taxed",
+        abi = "./SRC20Taxed/out/debug/src20_taxed-abi.json"
+    ),
+    Contract(
+        name = "FuniSwapV2Pair",
+        abi = "./FuniSwapV2Pair/out/debug/FuniSwapV2Pair-abi.json"
+    ),
+    Contract(
+        name = "FuniSwapV2StablePair",
+        abi = "./FuniSwapV2StablePair/out/debug/FuniSwapV2StablePair-abi.json"
+    ),
+    Contract(
+        name = "FuniSwapV2Router02",
+        abi = "./FuniSwapV2Router02/out/debug/FuniSwapV2Router02-abi.json"
+    ),
+    Contract(
+        name = "FuniSwapV2Factory",
+        abi = "./FuniSwapV2Factory/out/debug/FuniSwapV2Factory-abi.json"
+    ),
+    Script(
+        name = "FuniSwapV2AddLiquidityScript",
+        abi = "./FuniSwapV2AddLiquidityScript/out/debug/FuniSwapV2AddLiquidityScript-abi.json"
+    ),
+    Script(
+        name = "FuniSwapV2SwapScript",
+        abi = "./FuniSwapV2SwapScript/out/debug/FuniSwapV2SwapScript-abi.json"
+    )
+);
+
+/*
+ * ---- Contract binaries produced by `forc build`
+ */
+
+pub const SRC20_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/SRC20/out/debug/src20.bin"
+);
+
+pub const SRC20_TAXED_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/SRC20Taxed/out/debug/src20_taxed.bin"
+);
+
+pub const FUNI_PAIR_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/FuniSwapV2Pair/out/debug/FuniSwapV2Pair.bin"
+);
+
+pub const FUNI_STABLE_PAIR_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/FuniSwapV2StablePair/out/debug/FuniSwapV2StablePair.bin"
+);
+
+pub const FUNI_ROUTER02_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/FuniSwapV2Router02/out/debug/FuniSwapV2Router02.bin"
+);
+
+pub const FUNI_FACTORY_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/FuniSwapV2Factory/out/debug/FuniSwapV2Factory.bin"
+);
+
+pub const FUNI_ADD_LIQUIDITY_SCRIPT_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/FuniSwapV2AddLiquidityScript/out/debug/FuniSwapV2AddLiquidityScript.bin"
+);
+
+pub const FUNI_SWAP_SCRIPT_BINARY: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/FuniSwapV2SwapScript/out/debug/FuniSwapV2SwapScript.bin"
+);
+
+/*
+ * ---- Log decoding
+ */
+
+/*
+  Decoder of the logs of the given contract, knowing the logged types of every
+  contract of the SDK. The logs of the scripts come with the zero contract id.
+ */
+pub fn log_decoder(contract_id: ContractId) -> LogDecoder {
+    let wallet = WalletUnlocked::new_random(None);
+    let mut decoder = SRC20::new(contract_id, wallet.clone()).log_decoder();
+    decoder.merge(SRC20Taxed::new(contract_id, wallet.clone()).log_decoder());
+    decoder.merge(FuniSwapV2Pair::new(contract_id, wallet.clone()).log_decoder());
+    decoder.merge(FuniSwapV2StablePair::new(contract_id, wallet.clone()).log_decoder());
+    decoder.merge(FuniSwapV2Router02::new(contract_id, wallet.clone()).log_decoder());
+    decoder.merge(FuniSwapV2Factory::new(contract_id, wallet.clone()).log_decoder());
+    if contract_id == ContractId::zeroed() {
+        decoder.merge(FuniSwapV2AddLiquidityScript::new(wallet.clone(), FUNI_ADD_LIQUIDITY_SCRIPT_BINARY).log_decoder());
+        decoder.merge(FuniSwapV2SwapScript::new(wallet, FUNI_SWAP_SCRIPT_BINARY).log_decoder());
+    }
+    decoder
+}
@@ -0,0 +1,318 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand};
+use fuels::{
+    prelude::*,
+    crypto::SecretKey,
+    types::{
+        AssetId,
+        ContractId,
+        Identity,
+    }
+};
+use serde_json::{json, Value};
+
+use funi_sdk::{
+    Factory,
+    GenericMethods,
+    Pair,
+    Token,
+    TxConfig,
+};
+use funi_sdk::config::DEFAULT_GAS_LIMIT;
+use funi_sdk::instance::CallLog;
+use funi_sdk::manifest::Manifest;
+use funi_sdk::pair::{
+    create_funi_pair_configurables,
+    sort_tokens,
+};
+use funi_sdk::token::create_src20_configurables;
+use funi_sdk::utils::{
+    get_default_asset_id,
+    salt_for,
+    DEFAULT_SUB_ID,
+};
+
+type CliResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Deploy and operate a FuniSwapV2 instance. Every command prints its result as JSON.
+#[derive(Parser)]
+#[command(name = "funi", version)]
+struct Cli {
+    /// Fuel node to connect to
+    #[arg(long, env = "FUEL_NETWORK", default_value = "127.0.0.1:4000")]
+    network: String,
+
+    /// Secret key of the wallet signing the transactions
+    #[arg(long, env = "FUNI_SECRET_KEY", hide_env_values = true)]
+    secret_key: String,
+
+    /// Script gas limit of every transaction
+    #[arg(long, env = "FUNI_GAS_LIMIT", default_value_t = DEFAULT_GAS_LIMIT)]
+    gas_limit: u64,
+
+    /// Deployment manifest of the network. Contracts recorded in it are
+    /// reattached instead of redeployed, new deployments are added to it.
+    #[arg(long, env = "FUNI_MANIFEST")]
+    manifest: Option<PathBuf>,
+
+    /// Derive the deployment salts from this seed and the contract label
+    /// instead of generating random ones, so contract IDs are reproducible.
+    #[arg(long, env = "FUNI_SALT_SEED")]
+    salt_seed: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Deploy an SRC20 token, optionally minting an initial supply to the wallet
+    DeployToken {
+        /// Manifest label of the token, defaults to the symbol
+        #[arg(long)]
+        label: Option<String>,
+        #[arg(long, default_value = "Token")]
+        name: String,
+        #[arg(long, default_value = "TKN")]
+        symbol: String,
+        #[arg(long, default_value_t = 9)]
+        decimals: u8,
+        #[arg(long)]
+        supply: Option<u64>,
+    },
+    /// Deploy a pair contract for the given tokens, which are sorted first
+    DeployPair {
+        #[arg(long)]
+        token0: AssetId,
+        #[arg(long)]
+        token1: AssetId,
+    },
+    /// Deploy a pair for the sorted tokens and register it in the factory.
+    /// A new factory is deployed when none is given.
+    CreatePair {
+        #[arg(long)]
+        token_a: AssetId,
+        #[arg(long)]
+        token_b: AssetId,
+        #[arg(long)]
+        factory: Option<ContractId>,
+    },
+    /// Transfer both tokens to the pair and mint LP tokens to the wallet, in one
+    /// transaction running the add liquidity script
+    AddLiquidity {
+        #[arg(long)]
+        pair: ContractId,
+        #[arg(long)]
+        token0: AssetId,
+        #[arg(long)]
+        token1: AssetId,
+        #[arg(long)]
+        amount0: u64,
+        #[arg(long)]
+        amount1: u64,
+        #[arg(long, default_value_t = 0)]
+        min_liquidity: u64,
+    },
+    /// Swap an exact input amount of one pair token for the other, in one
+    /// transaction running the swap script
+    Swap {
+        #[arg(long)]
+        pair: ContractId,
+        #[arg(long)]
+        asset_in: AssetId,
+        #[arg(long)]
+        amount_in: u64,
+        #[arg(long, default_value_t = 0)]
+        min_amount_out: u64,
+    },
+    /// Burn LP tokens and send the underlying tokens to the wallet
+    RemoveLiquidity {
+        #[arg(long)]
+        pair: ContractId,
+        #[arg(long)]
+        liquidity: u64,
+    },
+    /// Print the reserves of a pair
+    Reserves {
+        #[arg(long)]
+        pair: ContractId,
+    },
+    /// Print the reserves, LP supply, fee and tokens of a pair
+    State {
+        #[arg(long)]
+        pair: ContractId,
+    },
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("{}", json!({ "error": e.to_string() }));
+            std::process::exit(1);
+        }
+    }
+}
+
+async fn run(cli: Cli) -> CliResult<Value> {
+    let provider = Provider::connect(&cli.network).await?;
+    let secret = SecretKey::from_str(&cli.secret_key)?;
+    let wallet = WalletUnlocked::new_from_private_key(secret, Some(provider));
+    let tx_config = TxConfig::default().with_gas_limit(cli.gas_limit);
+    let to = Identity::Address(Address::from(wallet.address()));
+    let seed = cli.salt_seed.as_deref();
+    let mut manifest = match &cli.manifest {
+        Some(path) => Some(Manifest::load_or_new(path, &cli.network)?),
+        None => None,
+    };
+
+    let output = match cli.command {
+        Command::DeployToken { label, name, symbol, decimals, supply } => {
+            let label = label.unwrap_or_else(|| symbol.clone());
+            let salt = salt_for(seed, &label);
+            let token = match manifest.as_mut() {
+                Some(manifest) => {
+                    manifest.token_or_deploy(&label, &name, &symbol, decimals, wallet, salt, tx_config).await?
+                }
+                None => {
+                    let configurables = create_src20_configurables(&name, &symbol, decimals)?;
+                    Token::deploy_with_configurables(wallet, salt, configurables, tx_config).await?
+                }
+            };
+            let mut mint_tx_id = None;
+            if let Some(amount) = supply {
+                token.clone().call_mint(to, DEFAULT_SUB_ID, amount).await?;
+                mint_tx_id = last_tx_id(&token.call_log);
+            }
+            json!({
+                "contract_id": token.contract_id().to_string(),
+                "asset_id": token.get_default_asset_id().to_string(),
+                "mint_tx_id": mint_tx_id,
+            })
+        }
+        Command::DeployPair { token0, token1 } => {
+            let pair = deploy_pair(manifest.as_mut(), seed, token0, token1, None, wallet, tx_config).await?;
+            json!({
+                "contract_id": pair.contract_id().to_string(),
+                "lp_asset_id": pair.get_default_asset_id().to_string(),
+            })
+        }
+        Command::CreatePair { token_a, token_b, factory } => {
+            let (token0, token1) = sort_tokens(token_a, token_b);
+            let factory = match (factory, manifest.as_mut()) {
+                (Some(contract_id), _) => Factory::attach(contract_id, wallet.clone(), tx_config),
+                (None, Some(manifest)) => manifest.factory_or_deploy(wallet.clone(), salt_for(seed, "factory"), tx_config).await?,
+                (None, None) => Factory::deploy(wallet.clone(), salt_for(seed, "factory"), tx_config).await?,
+            };
+            let pair = deploy_pair(
+                manifest.as_mut(),
+                seed,
+                token0,
+                token1,
+                Some(factory.contract_id()),
+                wallet,
+                tx_config
+            ).await?;
+            /* the pair may be registered already when reusing a manifest */
+            if factory.clone().call_get_pair(token0, token1, false).await?.is_none() {
+                factory.clone().call_create_pair(token0, token1, false, pair.contract_id()).await?;
+            }
+            json!({
+                "factory_id": factory.contract_id().to_string(),
+                "pair_id": pair.contract_id().to_string(),
+                "lp_asset_id": pair.get_default_asset_id().to_string(),
+                "token0": token0.to_string(),
+                "token1": token1.to_string(),
+                "tx_id": last_tx_id(&factory.call_log),
+            })
+        }
+        Command::AddLiquidity { pair, token0, token1, amount0, amount1, min_liquidity } => {
+            let pair = Pair::attach(pair, wallet, tx_config);
+            let liquidity = pair.clone()
+                .call_add_liquidity_script(token0, amount0, token1, amount1, min_liquidity, to)
+                .await?;
+            json!({
+                "liquidity": liquidity,
+                "tx_id": last_tx_id(&pair.call_log),
+            })
+        }
+        Command::Swap { pair, asset_in, amount_in, min_amount_out } => {
+            let pair = Pair::attach(pair, wallet, tx_config);
+            let amount_out = pair.clone()
+                .call_swap_script(asset_in, amount_in, min_amount_out, to)
+                .await?;
+            json!({
+                "amount_in": amount_in,
+                "amount_out": amount_out,
+                "tx_id": last_tx_id(&pair.call_log),
+            })
+        }
+        Command::RemoveLiquidity { pair, liquidity } => {
+            let pair = Pair::attach(pair, wallet, tx_config);
+            let (amount0, amount1) = pair.clone().call_burn(to, liquidity).await?;
+            json!({
+                "amount0": amount0,
+                "amount1": amount1,
+                "tx_id": last_tx_id(&pair.call_log),
+            })
+        }
+        Command::Reserves { pair } => {
+            let pair = Pair::attach(pair, wallet, tx_config);
+            let (reserve0, reserve1) = pair.clone().call_get_reserves().await?;
+            json!({
+                "pair_id": pair.contract_id().to_string(),
+                "lp_asset_id": get_default_asset_id(pair.contract_id()).to_string(),
+                "reserve0": reserve0,
+                "reserve1": reserve1,
+            })
+        }
+        Command::State { pair } => {
+            let pair = Pair::attach(pair, wallet, tx_config);
+            serde_json::to_value(pair.call_get_pair_state().await?)?
+        }
+    };
+
+    if let (Some(path), Some(manifest)) = (&cli.manifest, &manifest) {
+        manifest.save(path)?;
+    }
+
+    Ok(output)
+}
+
+/*
+  Deploy a pair, through the manifest when one is used
+ */
+async fn deploy_pair(
+    manifest: Option<&mut Manifest>,
+    seed: Option<&str>,
+    token0: AssetId,
+    token1: AssetId,
+    factory: Option<ContractId>,
+    wallet: WalletUnlocked,
+    tx_config: TxConfig,
+) -> CliResult<Pair> {
+    let (token0, token1) = sort_tokens(token0, token1);
+    let label = format!("{}-{}", token0, token1);
+    let salt = salt_for(seed, &label);
+    let pair = match manifest {
+        Some(manifest) => {
+            manifest.pair_or_deploy(&label, token0, token1, factory, wallet, salt, tx_config).await?
+        }
+        None => {
+            let mut configurables = create_funi_pair_configurables(token0, token1)?;
+            if let Some(factory) = factory {
+                configurables = configurables.with_factory(factory)?;
+            }
+            Pair::deploy_with_configurables(wallet, salt, configurables, tx_config).await?
+        }
+    };
+    Ok(pair)
+}
+
+fn last_tx_id(call_log: &CallLog) -> Option<String> {
+    call_log.last().and_then(|record| record.tx_id)
+}
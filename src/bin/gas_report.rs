@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+
+use funi_sdk::gas::{
+    load_thresholds,
+    GasReport,
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+/// Aggregate the gas samples recorded during a test run per contract method.
+/// Exits with an error when a method uses more gas than its threshold.
+#[derive(Parser)]
+#[command(name = "gas-report", version)]
+struct Cli {
+    /// JSON lines file written by the test harness
+    #[arg(long, default_value = "target/gas/calls.jsonl")]
+    calls: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Write the report to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// JSON object of the maximum gas per method, e.g. {"FuniSwapV2Pair::swap": 60000}
+    #[arg(long)]
+    thresholds: Option<PathBuf>,
+}
+
+fn main() {
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let report = GasReport::from_sink(&cli.calls)?;
+    let rendered = match cli.format {
+        Format::Table => report.to_table(),
+        Format::Json => report.to_json()? + "\n",
+    };
+    match &cli.output {
+        Some(path) => std::fs::write(path, rendered)?,
+        None => print!("{}", rendered),
+    }
+
+    if let Some(path) = &cli.thresholds {
+        let regressions = report.regressions(&load_thresholds(path)?);
+        for regression in &regressions {
+            eprintln!("gas regression: {}", regression);
+        }
+        if !regressions.is_empty() {
+            return Err(format!("{} method(s) above their gas threshold", regressions.len()).into());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use fuels::prelude::TxPolicies;
+
+pub const DEFAULT_GAS_LIMIT: u64 = 400000;
+pub const DEFAULT_MAX_FEE: u64 = 400000;
+
+/// Gas and fee settings applied to every transaction sent by a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TxConfig {
+    /// Script gas limit. Also forwarded as the call gas of payable methods.
+    pub gas_limit: u64,
+    /// Maximum fee the wallet is willing to pay, `None` lets the SDK estimate it.
+    pub max_fee: Option<u64>,
+    /// Tip paid to the block producer.
+    pub tip: Option<u64>,
+}
+
+impl Default for TxConfig {
+    fn default() -> Self {
+        Self {
+            gas_limit: DEFAULT_GAS_LIMIT,
+            max_fee: Some(DEFAULT_MAX_FEE),
+            tip: None,
+        }
+    }
+}
+
+impl TxConfig {
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    pub fn with_max_fee(mut self, max_fee: Option<u64>) -> Self {
+        self.max_fee = max_fee;
+        self
+    }
+
+    pub fn with_tip(mut self, tip: Option<u64>) -> Self {
+        self.tip = tip;
+        self
+    }
+
+    /*
+      Build the SDK transaction policies out of this config.
+     */
+    pub fn tx_policies(&self) -> TxPolicies {
+        let mut policies = TxPolicies::default()
+            .with_script_gas_limit(self.gas_limit);
+        if let Some(max_fee) = self.max_fee {
+            policies = policies.with_max_fee(max_fee);
+        }
+        if let Some(tip) = self.tip {
+            policies = policies.with_tip(tip);
+        }
+        policies
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use fuels::{
+    core::traits::{
+        Parameterize,
+        Tokenizable,
+    },
+    tx::Receipt,
+    types::{
+        errors::transaction::Reason,
+        AssetId,
+        ContractId,
+    },
+};
+
+use crate::abi::log_decoder;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Error returned by the fuels SDK: deployment, call revert, provider, etc.
+    Sdk(fuels::types::errors::Error),
+    /// The contract doesn't know about the given asset.
+    UnknownAsset(AssetId),
+    /// Reading or writing a file, e.g. the deployment manifest.
+    Io(std::io::Error),
+    /// (De)serializing the deployment manifest.
+    Json(serde_json::Error),
+    /// The deployment manifest holds a value that can't be parsed.
+    InvalidManifest(String),
+    /// The contract recorded in the manifest is not deployed on the network.
+    NotDeployed(ContractId),
+    /// The wallet of the client is not connected to a provider.
+    NoProvider,
+    /// A log of the contract holds a value that can't be indexed.
+    InvalidEvent(String),
+    /// A u256 returned by a contract doesn't fit the client side type.
+    Overflow(String),
+}
+
+impl Error {
+    /*
+      Reason of a reverted call: the debug form of the error logged by require(),
+      e.g. `InsufficientLiquidity`, or the VM panic reason.
+     */
+    pub fn revert_reason(&self) -> Option<&str> {
+        match self {
+            Error::Sdk(fuels::types::errors::Error::Transaction(Reason::Reverted { reason, .. })) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /*
+      True when the call reverted with the given error, e.g. `PairError::InsufficientLiquidity`.
+      The error logged by require() is decoded with its type, so a PairError and a
+      RouterError variant of the same name don't match each other.
+     */
+    pub fn is_revert<E>(&self, error: E) -> bool
+    where
+        E: Tokenizable + Parameterize + PartialEq + 'static,
+    {
+        self.revert_error::<E>() == Some(error)
+    }
+
+    /*
+      Last log of type E before the revert, the error the call reverted with
+     */
+    pub fn revert_error<E>(&self) -> Option<E>
+    where
+        E: Tokenizable + Parameterize + 'static,
+    {
+        let receipts = match self {
+            Error::Sdk(fuels::types::errors::Error::Transaction(Reason::Reverted { receipts, .. })) => receipts,
+            _ => return None,
+        };
+        /* the logs are keyed by the contract which emitted them */
+        let mut contract_ids: Vec<ContractId> = receipts.iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::LogData { id, .. } => Some(*id),
+                _ => None,
+            })
+            .collect();
+        contract_ids.sort();
+        contract_ids.dedup();
+
+        let mut decoder = log_decoder(ContractId::zeroed());
+        for contract_id in contract_ids {
+            decoder.merge(log_decoder(contract_id));
+        }
+        decoder.decode_logs_with_type::<E>(receipts).ok()?.pop()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Sdk(e) => write!(f, "fuels sdk error: {}", e),
+            Error::UnknownAsset(asset_id) => write!(f, "unknown asset: {}", asset_id),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::Json(e) => write!(f, "json error: {}", e),
+            Error::InvalidManifest(msg) => write!(f, "invalid deployment manifest: {}", msg),
+            Error::NotDeployed(contract_id) => write!(f, "contract {} is not deployed", contract_id),
+            Error::NoProvider => write!(f, "wallet is not connected to a provider"),
+            Error::InvalidEvent(msg) => write!(f, "invalid event: {}", msg),
+            Error::Overflow(msg) => write!(f, "overflow: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Sdk(e) => Some(e),
+            Error::Io(e) => Some(e),
+            Error::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<fuels::types::errors::Error> for Error {
+    fn from(e: fuels::types::errors::Error) -> Self {
+        Error::Sdk(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
@@ -0,0 +1,81 @@
+use fuels::{
+    prelude::*,
+    core::Configurables,
+    types::ContractId,
+    types::AssetId,
+};
+
+use crate::abi::{
+    FuniSwapV2Factory,
+    FUNI_FACTORY_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::Result;
+use crate::instance::{
+    CallLog,
+    ContractInstance,
+};
+use crate::utils::deploy_contract;
+
+impl ContractInstance<FuniSwapV2Factory<WalletUnlocked>> {
+    /*
+      Deploy a new factory
+     */
+    pub async fn deploy(wallet: WalletUnlocked, salt: Salt, tx_config: TxConfig) -> Result<Self> {
+        let contract_id = deploy_contract(
+            FUNI_FACTORY_BINARY,
+            &wallet,
+            salt,
+            Configurables::default(),
+            tx_config
+        ).await?;
+        Ok(Self::attach(contract_id, wallet, tx_config))
+    }
+
+    /*
+      Wrap an already deployed factory
+     */
+    pub fn attach(contract_id: ContractId, wallet: WalletUnlocked, tx_config: TxConfig) -> Self {
+        Self {
+            instance: FuniSwapV2Factory::new(contract_id, wallet.clone()),
+            contract_id,
+            wallet,
+            tx_config,
+            call_log: CallLog::default(),
+        }
+    }
+
+    /*
+      call create_pair() function. Registers an already deployed pair, the
+      pair must have been deployed with the sorted tokens and this factory.
+      `stable` is the type of the pair, a stable and a volatile pair of the
+      same tokens are registered separately.
+     */
+    pub async fn call_create_pair(self, token_a: AssetId, token_b: AssetId, stable: bool, pair: ContractId) -> Result<ContractId> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .create_pair(token_a, token_b, stable, pair)
+        .with_contract_ids(&[pair.into()])
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("create_pair", result))
+    }
+
+    /*
+      call get_pair() function.
+     */
+    pub async fn call_get_pair(self, token_a: AssetId, token_b: AssetId, stable: bool) -> Result<Option<ContractId>> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_pair(token_a, token_b, stable)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("get_pair", result))
+    }
+}
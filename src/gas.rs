@@ -0,0 +1,153 @@
+//! Gas usage per ABI method, aggregated from the calls recorded by the clients.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::instance::CallRecord;
+
+/// Gas used by a single call, as written to a `CallLog` sink.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasSample {
+    pub contract: String,
+    pub method: String,
+    pub gas_used: u64,
+}
+
+impl GasSample {
+    /*
+      Key of the method in reports and threshold files, e.g. `FuniSwapV2Pair::mint`
+     */
+    pub fn key(&self) -> String {
+        format!("{}::{}", self.contract, self.method)
+    }
+}
+
+impl From<&CallRecord> for GasSample {
+    fn from(record: &CallRecord) -> Self {
+        Self {
+            contract: record.contract.to_string(),
+            method: record.method.to_string(),
+            gas_used: record.gas_used,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasStats {
+    pub calls: u64,
+    pub min: u64,
+    pub max: u64,
+    pub mean: u64,
+}
+
+/// Maximum gas allowed per method key.
+pub type GasThresholds = BTreeMap<String, u64>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasReport {
+    pub methods: BTreeMap<String, GasStats>,
+}
+
+impl GasReport {
+    pub fn from_samples<'a>(samples: impl IntoIterator<Item = &'a GasSample>) -> Self {
+        let mut totals: BTreeMap<String, (GasStats, u128)> = BTreeMap::new();
+        for sample in samples {
+            let (stats, total) = totals.entry(sample.key()).or_insert((
+                GasStats { calls: 0, min: u64::MAX, max: 0, mean: 0 },
+                0,
+            ));
+            stats.calls += 1;
+            stats.min = stats.min.min(sample.gas_used);
+            stats.max = stats.max.max(sample.gas_used);
+            *total += sample.gas_used as u128;
+        }
+
+        let methods = totals
+            .into_iter()
+            .map(|(key, (mut stats, total))| {
+                stats.mean = (total / stats.calls as u128) as u64;
+                (key, stats)
+            })
+            .collect();
+        Self { methods }
+    }
+
+    pub fn from_records(records: &[CallRecord]) -> Self {
+        let samples: Vec<GasSample> = records.iter().map(GasSample::from).collect();
+        Self::from_samples(&samples)
+    }
+
+    /*
+      Load the samples written by a CallLog sink
+     */
+    pub fn from_sink(path: impl AsRef<Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let samples = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<std::result::Result<Vec<GasSample>, _>>()?;
+        Ok(Self::from_samples(&samples))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_table(&self) -> String {
+        let width = self.methods.keys().map(|key| key.len()).max().unwrap_or(0).max(6);
+        let mut table = format!(
+            "{:<width$} {:>6} {:>10} {:>10} {:>10}\n",
+            "method", "calls", "min", "max", "mean",
+            width = width
+        );
+        for (key, stats) in &self.methods {
+            table.push_str(&format!(
+                "{:<width$} {:>6} {:>10} {:>10} {:>10}\n",
+                key, stats.calls, stats.min, stats.max, stats.mean,
+                width = width
+            ));
+        }
+        table
+    }
+
+    /*
+      Methods whose max gas is above their threshold. Methods without
+      a threshold are not checked.
+     */
+    pub fn regressions(&self, thresholds: &GasThresholds) -> Vec<GasRegression> {
+        self.methods
+            .iter()
+            .filter_map(|(key, stats)| {
+                let threshold = *thresholds.get(key)?;
+                (stats.max > threshold).then(|| GasRegression {
+                    method: key.clone(),
+                    max: stats.max,
+                    threshold,
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn load_thresholds(path: impl AsRef<Path>) -> Result<GasThresholds> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasRegression {
+    pub method: String,
+    pub max: u64,
+    pub threshold: u64,
+}
+
+impl fmt::Display for GasRegression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} used {} gas, threshold is {}", self.method, self.max, self.threshold)
+    }
+}
@@ -0,0 +1,356 @@
+//! Rebuilds the history of a pair from the receipts of its transactions.
+//!
+//! `MintEvent`, `BurnEvent` and `SwapEvent` logged by the pair are replayed in
+//! order to track the swap volume, and the reserves are taken from the
+//! `SyncEvent` logged with each of them. The LP supply is taken from
+//! the `Mint` and `Burn` receipts of the LP asset, since the events don't carry
+//! the liquidity amount. Every event produces a [`PoolSnapshot`], which can be
+//! stored as JSON lines.
+//!
+//! The node is read block by block. With a checkpoint file, the state and the
+//! height of the last indexed block are saved after every page of blocks, and
+//! the next run resumes from there instead of from genesis. A block is indexed
+//! whole or not at all.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use fuels::{
+    prelude::*,
+    client::{
+        PageDirection,
+        PaginationRequest,
+    },
+    core::codec::LogDecoder,
+    tx::Receipt,
+    types::{
+        tx_status::TxStatus,
+        ContractId,
+        U256,
+    }
+};
+use serde::{Deserialize, Serialize};
+
+use crate::abi::{
+    BurnEvent,
+    MintEvent,
+    SwapEvent,
+    SyncEvent,
+};
+use crate::error::{Error, Result};
+use crate::Pair;
+
+/// Blocks requested from the node per page.
+const PAGE_SIZE: i32 = 100;
+
+/// A pair event, with the amounts as logged by the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolEvent {
+    Mint { amount0: u128, amount1: u128 },
+    Burn { amount0: u128, amount1: u128 },
+    Swap { amount0_in: u128, amount1_in: u128, amount0_out: u128, amount1_out: u128 },
+}
+
+/// Pair state after replaying all the events seen so far.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolState {
+    pub reserve0: u128,
+    pub reserve1: u128,
+    /// Cumulative swap input of token0 / token1.
+    pub volume0: u128,
+    pub volume1: u128,
+    /// LP supply, including the MINIMUM_LIQUIDITY locked in the pair.
+    pub total_supply: u128,
+}
+
+impl PoolState {
+    /*
+      lp_minted / lp_burned are the LP amounts of the Mint / Burn receipts of the same transaction.
+      Without a sync, tokens sent to the pair outside of mint and swap are not seen, as the
+      reserves are derived from the event amounts. An event taking more than the state holds
+      means events were missed, it is rejected and the state is left unchanged.
+     */
+    pub fn apply(&mut self, event: &PoolEvent, lp_minted: u128, lp_burned: u128) -> Result<()> {
+        let mut state = *self;
+        match *event {
+            PoolEvent::Mint { amount0, amount1 } => {
+                state.reserve0 = add("reserve0", state.reserve0, amount0)?;
+                state.reserve1 = add("reserve1", state.reserve1, amount1)?;
+            }
+            PoolEvent::Burn { amount0, amount1 } => {
+                state.reserve0 = sub("reserve0", state.reserve0, amount0)?;
+                state.reserve1 = sub("reserve1", state.reserve1, amount1)?;
+            }
+            PoolEvent::Swap { amount0_in, amount1_in, amount0_out, amount1_out } => {
+                state.reserve0 = sub("reserve0", add("reserve0", state.reserve0, amount0_in)?, amount0_out)?;
+                state.reserve1 = sub("reserve1", add("reserve1", state.reserve1, amount1_in)?, amount1_out)?;
+                state.volume0 = add("volume0", state.volume0, amount0_in)?;
+                state.volume1 = add("volume1", state.volume1, amount1_in)?;
+            }
+        }
+        state.total_supply = sub("total_supply", add("total_supply", state.total_supply, lp_minted)?, lp_burned)?;
+        *self = state;
+        Ok(())
+    }
+
+    /*
+      Reserves logged by the pair, they always match the contract storage
+     */
+    pub fn sync(&mut self, reserve0: u128, reserve1: u128) {
+        self.reserve0 = reserve0;
+        self.reserve1 = reserve1;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolSnapshot {
+    pub tx_id: String,
+    /// Unix timestamp of the block, when known.
+    pub time: Option<i64>,
+    pub event: PoolEvent,
+    /// State after the event.
+    pub state: PoolState,
+}
+
+/// What the indexer saves to resume from: the state after the last indexed block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexerCheckpoint {
+    /// Height of the last indexed block, None before the first one.
+    pub block_height: Option<u32>,
+    pub state: PoolState,
+}
+
+pub struct PairIndexer {
+    contract_id: ContractId,
+    provider: Provider,
+    decoder: LogDecoder,
+    /// Last indexed block, the next sync starts after it.
+    block_height: Option<u32>,
+    state: PoolState,
+    /// Snapshots of the events replayed by this indexer, not the ones before its checkpoint.
+    snapshots: Vec<PoolSnapshot>,
+    /// File the checkpoint is saved to after every page of blocks.
+    checkpoint: Option<PathBuf>,
+}
+
+impl PairIndexer {
+    pub fn new(pair: &Pair) -> Result<Self> {
+        let provider = pair.wallet.provider().ok_or(Error::NoProvider)?.clone();
+        Ok(Self {
+            contract_id: pair.contract_id,
+            provider,
+            decoder: pair.get_instance().log_decoder(),
+            block_height: None,
+            state: PoolState::default(),
+            snapshots: Vec::new(),
+            checkpoint: None,
+        })
+    }
+
+    /*
+      Save the progress to the given file, and resume from it when it exists.
+      The checkpoint has to be one of the same pair.
+     */
+    pub fn with_checkpoint(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        if path.as_ref().exists() {
+            let checkpoint: IndexerCheckpoint = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+            self.block_height = checkpoint.block_height;
+            self.state = checkpoint.state;
+        }
+        self.checkpoint = Some(path.as_ref().to_path_buf());
+        Ok(self)
+    }
+
+    pub fn state(&self) -> PoolState {
+        self.state
+    }
+
+    pub fn block_height(&self) -> Option<u32> {
+        self.block_height
+    }
+
+    pub fn snapshots(&self) -> &[PoolSnapshot] {
+        &self.snapshots
+    }
+
+    /*
+      Fetch the blocks added since the last indexed one and replay their
+      transactions touching the pair. Returns the number of new snapshots.
+     */
+    pub async fn sync(&mut self) -> Result<usize> {
+        let start = self.snapshots.len();
+        loop {
+            /* the cursor of the blocks is their height, the page starts after it */
+            let page = self.provider.get_blocks(PaginationRequest {
+                cursor: self.block_height.map(|height| height.to_string()),
+                results: PAGE_SIZE,
+                direction: PageDirection::Forward,
+            }).await?;
+
+            for block in page.results {
+                let time = block.header.time.map(|time| time.timestamp());
+                let mut transactions = Vec::new();
+                for tx_id in &block.transactions {
+                    let Some(response) = self.provider.get_transaction_by_id(tx_id).await? else {
+                        continue;
+                    };
+                    if let TxStatus::Success { receipts } = response.status {
+                        transactions.push((tx_id.to_string(), receipts));
+                    }
+                }
+                self.apply_block(block.header.height, time, &transactions)?;
+            }
+            self.save_checkpoint()?;
+            if !page.has_next_page {
+                break;
+            }
+        }
+        Ok(self.snapshots.len() - start)
+    }
+
+    fn save_checkpoint(&self) -> Result<()> {
+        if let Some(path) = &self.checkpoint {
+            let checkpoint = IndexerCheckpoint {
+                block_height: self.block_height,
+                state: self.state,
+            };
+            std::fs::write(path, serde_json::to_string(&checkpoint)?)?;
+        }
+        Ok(())
+    }
+
+    /*
+      Replay the (tx id, receipts) of the successful transactions of a block, in order.
+      The block is replayed into a copy of the state, which is committed with the block
+      height only when every transaction applies, so a failed block is retried whole.
+     */
+    pub fn apply_block(&mut self, height: u32, time: Option<i64>, transactions: &[(String, Vec<Receipt>)]) -> Result<()> {
+        let mut state = self.state;
+        let mut snapshots = Vec::new();
+        for (tx_id, receipts) in transactions {
+            self.replay(&mut state, &mut snapshots, tx_id, time, receipts)?;
+        }
+        self.state = state;
+        self.snapshots.extend(snapshots);
+        self.block_height = Some(height);
+        Ok(())
+    }
+
+    fn replay(
+        &self,
+        state: &mut PoolState,
+        snapshots: &mut Vec<PoolSnapshot>,
+        tx_id: &str,
+        time: Option<i64>,
+        receipts: &[Receipt],
+    ) -> Result<()> {
+        let mut lp_minted: u128 = 0;
+        let mut lp_burned: u128 = 0;
+        /* _update logs the SyncEvent before the event of the call */
+        let mut synced: Option<(u128, u128)> = None;
+        for receipt in receipts {
+            match receipt {
+                Receipt::Mint { contract_id, val, .. } if *contract_id == self.contract_id => {
+                    lp_minted += *val as u128;
+                }
+                Receipt::Burn { contract_id, val, .. } if *contract_id == self.contract_id => {
+                    lp_burned += *val as u128;
+                }
+                Receipt::LogData { .. } => {
+                    if let Some(event) = self.decode_sync(receipt)? {
+                        synced = Some((to_u128(event.reserve0)?, to_u128(event.reserve1)?));
+                    } else if let Some(event) = self.decode_event(receipt)? {
+                        state.apply(&event, lp_minted, lp_burned)?;
+                        if let Some((reserve0, reserve1)) = synced.take() {
+                            state.sync(reserve0, reserve1);
+                        }
+                        (lp_minted, lp_burned) = (0, 0);
+                        snapshots.push(PoolSnapshot {
+                            tx_id: tx_id.to_string(),
+                            time,
+                            event,
+                            state: *state,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_sync(&self, receipt: &Receipt) -> Result<Option<SyncEvent>> {
+        Ok(self.decoder.decode_logs_with_type::<SyncEvent>(std::slice::from_ref(receipt))?.pop())
+    }
+
+    /*
+      The decoder only knows the log ids of this pair, logs of other contracts decode to nothing
+     */
+    fn decode_event(&self, receipt: &Receipt) -> Result<Option<PoolEvent>> {
+        let receipt = std::slice::from_ref(receipt);
+        if let Some(e) = self.decoder.decode_logs_with_type::<MintEvent>(receipt)?.pop() {
+            return Ok(Some(PoolEvent::Mint {
+                amount0: to_u128(e.amount0)?,
+                amount1: to_u128(e.amount1)?,
+            }));
+        }
+        if let Some(e) = self.decoder.decode_logs_with_type::<BurnEvent>(receipt)?.pop() {
+            return Ok(Some(PoolEvent::Burn {
+                amount0: to_u128(e.amount0)?,
+                amount1: to_u128(e.amount1)?,
+            }));
+        }
+        if let Some(e) = self.decoder.decode_logs_with_type::<SwapEvent>(receipt)?.pop() {
+            return Ok(Some(PoolEvent::Swap {
+                amount0_in: to_u128(e.amount0_in)?,
+                amount1_in: to_u128(e.amount1_in)?,
+                amount0_out: e.amount0_out as u128,
+                amount1_out: e.amount1_out as u128,
+            }));
+        }
+        Ok(None)
+    }
+
+    /*
+      Write all the snapshots to a JSON lines file, replacing its content
+     */
+    pub fn write_json_lines(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut file = File::create(path)?;
+        for snapshot in &self.snapshots {
+            writeln!(file, "{}", serde_json::to_string(snapshot)?)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn read_json_lines(path: impl AsRef<Path>) -> Result<Vec<PoolSnapshot>> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect::<std::result::Result<Vec<PoolSnapshot>, _>>()?)
+}
+
+fn add(name: &str, a: u128, b: u128) -> Result<u128> {
+    a.checked_add(b).ok_or_else(|| Error::Overflow(format!("{} {} + {}", name, a, b)))
+}
+
+/*
+  The pair never gives out more than it holds, an underflow means missed events
+ */
+fn sub(name: &str, a: u128, b: u128) -> Result<u128> {
+    a.checked_sub(b).ok_or_else(|| Error::InvalidEvent(format!("{} {} below {}", name, a, b)))
+}
+
+/*
+  Event amounts are u256 but the pair never moves more than u64::MAX
+ */
+fn to_u128(amount: U256) -> Result<u128> {
+    if amount > U256::from(u128::MAX) {
+        return Err(Error::InvalidEvent(format!("amount {} above u128::MAX", amount)));
+    }
+    Ok(amount.as_u128())
+}
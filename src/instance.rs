@@ -0,0 +1,219 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use fuels::{
+    prelude::*,
+    programs::responses::CallResponse,
+    types::ContractId,
+    types::{
+        AssetId,
+        Identity,
+    }
+};
+
+use crate::config::TxConfig;
+use crate::error::Result;
+use crate::gas::GasSample;
+use crate::utils::get_default_asset_id;
+
+#[derive(Clone)]
+pub struct ContractInstance<T> {
+    pub instance: T,
+    pub contract_id: ContractId,
+    pub wallet: WalletUnlocked,
+    pub tx_config: TxConfig,
+    pub call_log: CallLog,
+}
+
+/// A single contract call made through one of the client wrappers. Only what
+/// identifies the call is kept, its receipts can be fetched with the tx id.
+#[derive(Clone, Debug)]
+pub struct CallRecord {
+    pub contract_id: ContractId,
+    /// Contract type name, e.g. `FuniSwapV2Pair`.
+    pub contract: &'static str,
+    /// ABI method name.
+    pub method: &'static str,
+    pub tx_id: Option<String>,
+    pub gas_used: u64,
+}
+
+/// Calls made through a client. Clones of the client share the same log,
+/// so the wrappers consuming `self` still record into the caller's log.
+#[derive(Clone, Debug, Default)]
+pub struct CallLog {
+    records: Arc<Mutex<Vec<CallRecord>>>,
+    /// JSON lines file every call's gas usage is appended to.
+    sink: Option<Arc<PathBuf>>,
+    /// Last failure to write to the sink, the call itself is still recorded.
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl CallLog {
+    /*
+      Call log that also appends a GasSample line per call to the given file.
+      The file is truncated first.
+     */
+    pub fn with_sink(path: impl AsRef<Path>) -> Result<Self> {
+        if let Some(dir) = path.as_ref().parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, "")?;
+        Ok(Self {
+            records: Arc::default(),
+            sink: Some(Arc::new(path.as_ref().to_path_buf())),
+            last_error: Arc::default(),
+        })
+    }
+
+    pub fn records(&self) -> Vec<CallRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    pub fn last(&self) -> Option<CallRecord> {
+        self.records.lock().unwrap().last().cloned()
+    }
+
+    /*
+      Error of the last sample that couldn't be written to the sink
+     */
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().unwrap().clone()
+    }
+
+    pub fn push(&self, record: CallRecord) {
+        let mut records = self.records.lock().unwrap();
+        if let Some(sink) = &self.sink {
+            /* written under the lock so lines of concurrent calls don't interleave */
+            if let Err(e) = append_sample(sink, &GasSample::from(&record)) {
+                *self.last_error.lock().unwrap() = Some(format!("{}: {}", sink.display(), e));
+            }
+        }
+        records.push(record);
+    }
+}
+
+fn append_sample(path: &Path, sample: &GasSample) -> Result<()> {
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(sample)?)?;
+    Ok(())
+}
+
+impl<T> ContractInstance<T> {
+    pub fn with_call_log(mut self, call_log: CallLog) -> Self {
+        self.call_log = call_log;
+        self
+    }
+
+    pub fn with_tx_config(mut self, tx_config: TxConfig) -> Self {
+        self.tx_config = tx_config;
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.tx_config = self.tx_config.with_gas_limit(gas_limit);
+        self
+    }
+
+    /*
+      Last call made to this contract. The log may be shared with other
+      contracts, so their calls are skipped.
+     */
+    pub fn last_call(&self) -> Option<CallRecord> {
+        self.call_log
+            .records()
+            .into_iter()
+            .rev()
+            .find(|record| record.contract_id == self.contract_id)
+    }
+
+    /*
+      Record a call response in the call log and return its value
+     */
+    pub(crate) fn record<V>(&self, method: &'static str, response: CallResponse<V>) -> V {
+        self.call_log.push(CallRecord {
+            contract_id: self.contract_id,
+            contract: contract_name::<T>(),
+            method,
+            tx_id: response.tx_id.map(|tx_id| tx_id.to_string()),
+            gas_used: response.gas_used,
+        });
+        response.value
+    }
+}
+
+/*
+  Last path segment of the abigen type, without its generics
+ */
+fn contract_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[allow(async_fn_in_trait)]
+pub trait GenericMethods {
+    // return current instance contract ID
+    fn contract_id(&self) -> ContractId;
+
+    // return the identity based on the wallet
+    fn deployer_identity(&self) -> Identity;
+
+    fn deployer_wallet(&self) -> WalletUnlocked;
+
+    // return the balance of wallet which was used to deploy the instance
+    async fn deployer_balance(&self, asset_id: AssetId) -> Result<u64>;
+
+    // get the default AssetID of the deployed contract
+    fn get_default_asset_id(&self) -> AssetId;
+}
+
+impl<T> GenericMethods for ContractInstance<T>
+where
+    T: Clone
+{
+    fn contract_id(&self) -> ContractId {
+        self.contract_id
+    }
+
+    fn deployer_identity(&self) -> Identity {
+        Identity::Address(
+            Address::from(
+                self.wallet.address()
+            )
+        )
+    }
+
+    fn deployer_wallet(&self) -> WalletUnlocked {
+        self.wallet.clone()
+    }
+
+    async fn deployer_balance(&self, asset_id: AssetId) -> Result<u64> {
+        Ok(self.wallet.get_asset_balance(&asset_id).await?)
+    }
+
+    fn get_default_asset_id(&self) -> AssetId {
+        get_default_asset_id(self.contract_id)
+    }
+}
+
+#[allow(async_fn_in_trait)]
+pub trait WalletTransfer {
+    // returns the transaction id of the transfer, sent with the tx policies of the given config
+    async fn transfer_to_contract(&self, contract: ContractId, asset: AssetId, amount: u64, tx_config: TxConfig) -> Result<String>;
+}
+
+impl WalletTransfer for WalletUnlocked {
+    async fn transfer_to_contract(&self, contract: ContractId, asset: AssetId, amount: u64, tx_config: TxConfig) -> Result<String> {
+        let bech32_contract_id = Bech32ContractId::from(contract);
+        let (tx_id, _receipts) = self.force_transfer_to_contract(
+            &bech32_contract_id,
+            amount,
+            asset,
+            tx_config.tx_policies()
+        ).await?;
+        Ok(tx_id.to_string())
+    }
+}
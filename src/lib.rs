@@ -0,0 +1,64 @@
+//! Off-chain client library for the FuniSwapV2 contracts.
+//!
+//! Every deployed contract is wrapped in a [`ContractInstance`] which keeps the
+//! abigen instance together with the wallet and the [`TxConfig`] used for calls.
+//! The typed clients below are just aliases of it, with the contract specific
+//! methods implemented in the matching module.
+
+pub mod abi;
+pub mod config;
+pub mod error;
+pub mod factory;
+pub mod gas;
+pub mod indexer;
+pub mod instance;
+pub mod manifest;
+pub mod model;
+pub mod pair;
+pub mod position;
+pub mod router;
+pub mod scripts;
+pub mod stable_pair;
+pub mod taxed_token;
+pub mod token;
+pub mod utils;
+
+use fuels::prelude::WalletUnlocked;
+
+pub use crate::abi::{
+    FactoryError,
+    FuniSwapV2Factory,
+    FuniSwapV2Pair,
+    FuniSwapV2Router02,
+    FuniSwapV2StablePair,
+    MathError,
+    PairError,
+    RouterError,
+    SRC20,
+    SRC20Taxed,
+};
+pub use crate::config::TxConfig;
+pub use crate::error::{Error, Result};
+pub use crate::instance::{
+    ContractInstance,
+    GenericMethods,
+    WalletTransfer,
+};
+
+/// SRC20 token client.
+pub type Token = ContractInstance<SRC20<WalletUnlocked>>;
+
+/// SRC20Taxed test token client.
+pub type TaxedToken = ContractInstance<SRC20Taxed<WalletUnlocked>>;
+
+/// FuniSwapV2Pair client.
+pub type Pair = ContractInstance<FuniSwapV2Pair<WalletUnlocked>>;
+
+/// FuniSwapV2StablePair client.
+pub type StablePair = ContractInstance<FuniSwapV2StablePair<WalletUnlocked>>;
+
+/// FuniSwapV2Router02 client.
+pub type Router = ContractInstance<FuniSwapV2Router02<WalletUnlocked>>;
+
+/// FuniSwapV2Factory client.
+pub type Factory = ContractInstance<FuniSwapV2Factory<WalletUnlocked>>;
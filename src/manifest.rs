@@ -0,0 +1,341 @@
+//! Deployment manifest: one JSON file per network recording every contract
+//! deployed through it, so that later runs reattach to the existing contracts
+//! instead of deploying new ones.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use fuels::{
+    prelude::*,
+    types::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    }
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::abi::{
+    SRC20_BINARY,
+    FUNI_PAIR_BINARY,
+    FUNI_ROUTER02_BINARY,
+    FUNI_FACTORY_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::{Error, Result};
+use crate::pair::{
+    create_funi_pair_configurables,
+    sort_tokens,
+};
+use crate::router::create_funi_router02_pair_configurables;
+use crate::token::create_src20_configurables;
+use crate::{Factory, Pair, Router, Token};
+
+/// A single deployed contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployment {
+    pub contract_id: String,
+    pub salt: String,
+    /// sha256 of the binary produced by `forc build`, before configurables are applied.
+    pub bytecode_hash: String,
+    /// Configurables the contract was deployed with, by name.
+    #[serde(default)]
+    pub configurables: BTreeMap<String, String>,
+}
+
+impl Deployment {
+    pub fn new(
+        contract_id: ContractId,
+        salt: Salt,
+        binary: &str,
+        configurables: BTreeMap<String, String>,
+    ) -> Result<Self> {
+        Ok(Self {
+            contract_id: contract_id.to_string(),
+            salt: salt.to_string(),
+            bytecode_hash: bytecode_hash(binary)?,
+            configurables,
+        })
+    }
+
+    pub fn contract_id(&self) -> Result<ContractId> {
+        ContractId::from_str(&self.contract_id)
+            .map_err(|e| Error::InvalidManifest(format!("contract id {}: {}", self.contract_id, e)))
+    }
+
+    pub fn salt(&self) -> Result<Salt> {
+        Salt::from_str(&self.salt)
+            .map_err(|e| Error::InvalidManifest(format!("salt {}: {}", self.salt, e)))
+    }
+}
+
+/// All contracts deployed on one network. Tokens, pairs and routers are keyed
+/// by a label chosen at deployment time.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub network: String,
+    #[serde(default)]
+    pub tokens: BTreeMap<String, Deployment>,
+    #[serde(default)]
+    pub pairs: BTreeMap<String, Deployment>,
+    #[serde(default)]
+    pub routers: BTreeMap<String, Deployment>,
+    #[serde(default)]
+    pub factory: Option<Deployment>,
+}
+
+impl Manifest {
+    pub fn new(network: &str) -> Self {
+        Self {
+            network: network.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /*
+      Default location of the manifest of a network inside `dir`
+     */
+    pub fn path_for(dir: impl AsRef<Path>, network: &str) -> PathBuf {
+        let file_name: String = network
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+            .collect();
+        dir.as_ref().join(format!("{}.json", file_name))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /*
+      Load the manifest or start an empty one when the file doesn't exist yet.
+      A manifest recorded for another network is rejected.
+     */
+    pub fn load_or_new(path: impl AsRef<Path>, network: &str) -> Result<Self> {
+        if !path.as_ref().exists() {
+            return Ok(Self::new(network));
+        }
+        let manifest = Self::load(path)?;
+        if manifest.network != network {
+            return Err(Error::InvalidManifest(format!(
+                "manifest of network {} used for {}", manifest.network, network
+            )));
+        }
+        Ok(manifest)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        if let Some(dir) = path.as_ref().parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /*
+      ---- Reattach to recorded deployments
+     */
+
+    pub async fn token(&self, label: &str, wallet: WalletUnlocked, tx_config: TxConfig) -> Result<Option<Token>> {
+        match self.tokens.get(label) {
+            Some(deployment) => {
+                let contract_id = ensure_deployed(deployment, SRC20_BINARY, &wallet).await?;
+                Ok(Some(Token::attach(contract_id, wallet, tx_config)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn pair(&self, label: &str, wallet: WalletUnlocked, tx_config: TxConfig) -> Result<Option<Pair>> {
+        match self.pairs.get(label) {
+            Some(deployment) => {
+                let contract_id = ensure_deployed(deployment, FUNI_PAIR_BINARY, &wallet).await?;
+                Ok(Some(Pair::attach(contract_id, wallet, tx_config)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn router(&self, label: &str, wallet: WalletUnlocked, tx_config: TxConfig) -> Result<Option<Router>> {
+        match self.routers.get(label) {
+            Some(deployment) => {
+                let contract_id = ensure_deployed(deployment, FUNI_ROUTER02_BINARY, &wallet).await?;
+                Ok(Some(Router::attach(contract_id, wallet, tx_config)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn factory(&self, wallet: WalletUnlocked, tx_config: TxConfig) -> Result<Option<Factory>> {
+        match &self.factory {
+            Some(deployment) => {
+                let contract_id = ensure_deployed(deployment, FUNI_FACTORY_BINARY, &wallet).await?;
+                Ok(Some(Factory::attach(contract_id, wallet, tx_config)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /*
+      ---- Reattach when recorded, otherwise deploy and record
+     */
+
+    pub async fn token_or_deploy(
+        &mut self,
+        label: &str,
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+        wallet: WalletUnlocked,
+        salt: Salt,
+        tx_config: TxConfig,
+    ) -> Result<Token> {
+        let recorded = BTreeMap::from([
+            ("name".to_string(), name.to_string()),
+            ("symbol".to_string(), symbol.to_string()),
+            ("decimals".to_string(), decimals.to_string()),
+        ]);
+        ensure_configurables(label, self.tokens.get(label), &recorded)?;
+        if let Some(token) = self.token(label, wallet.clone(), tx_config).await? {
+            return Ok(token);
+        }
+        let configurables = create_src20_configurables(name, symbol, decimals)?;
+        let token = Token::deploy_with_configurables(wallet, salt, configurables, tx_config).await?;
+        self.tokens.insert(
+            label.to_string(),
+            Deployment::new(token.contract_id, salt, SRC20_BINARY, recorded)?,
+        );
+        Ok(token)
+    }
+
+    pub async fn pair_or_deploy(
+        &mut self,
+        label: &str,
+        token_a: AssetId,
+        token_b: AssetId,
+        factory: Option<ContractId>,
+        wallet: WalletUnlocked,
+        salt: Salt,
+        tx_config: TxConfig,
+    ) -> Result<Pair> {
+        /* recorded in the order the pair holds them */
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        let mut recorded = token_configurables(token0, token1);
+        if let Some(factory) = factory {
+            recorded.insert("factory".to_string(), factory.to_string());
+        }
+        ensure_configurables(label, self.pairs.get(label), &recorded)?;
+        if let Some(pair) = self.pair(label, wallet.clone(), tx_config).await? {
+            return Ok(pair);
+        }
+        let mut configurables = create_funi_pair_configurables(token0, token1)?;
+        if let Some(factory) = factory {
+            configurables = configurables.with_factory(factory)?;
+        }
+        let pair = Pair::deploy_with_configurables(wallet, salt, configurables, tx_config).await?;
+        self.pairs.insert(
+            label.to_string(),
+            Deployment::new(pair.contract_id, salt, FUNI_PAIR_BINARY, recorded)?,
+        );
+        Ok(pair)
+    }
+
+    pub async fn router_or_deploy(
+        &mut self,
+        label: &str,
+        token0: AssetId,
+        token1: AssetId,
+        pair: ContractId,
+        wallet: WalletUnlocked,
+        salt: Salt,
+        tx_config: TxConfig,
+    ) -> Result<Router> {
+        let mut recorded = token_configurables(token0, token1);
+        recorded.insert("pair".to_string(), pair.to_string());
+        ensure_configurables(label, self.routers.get(label), &recorded)?;
+        if let Some(router) = self.router(label, wallet.clone(), tx_config).await? {
+            return Ok(router);
+        }
+        let configurables = create_funi_router02_pair_configurables(token0, token1, pair)?;
+        let router = Router::deploy_with_configurables(wallet, salt, configurables, tx_config).await?;
+        self.routers.insert(
+            label.to_string(),
+            Deployment::new(router.contract_id, salt, FUNI_ROUTER02_BINARY, recorded)?,
+        );
+        Ok(router)
+    }
+
+    pub async fn factory_or_deploy(
+        &mut self,
+        wallet: WalletUnlocked,
+        salt: Salt,
+        tx_config: TxConfig,
+    ) -> Result<Factory> {
+        if let Some(factory) = self.factory(wallet.clone(), tx_config).await? {
+            return Ok(factory);
+        }
+        let factory = Factory::deploy(wallet, salt, tx_config).await?;
+        self.factory = Some(
+            Deployment::new(factory.contract_id, salt, FUNI_FACTORY_BINARY, BTreeMap::new())?
+        );
+        Ok(factory)
+    }
+}
+
+/*
+  sha256 of a contract binary
+ */
+pub fn bytecode_hash(binary: &str) -> Result<String> {
+    let bytecode = std::fs::read(binary)?;
+    let mut hasher = Sha256::new();
+    hasher.update(bytecode);
+    Ok(Bytes32::from(<[u8; 32]>::from(hasher.finalize())).to_string())
+}
+
+fn token_configurables(token0: AssetId, token1: AssetId) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("token0".to_string(), token0.to_string()),
+        ("token1".to_string(), token1.to_string()),
+    ])
+}
+
+/*
+  A label reattaches only to a deployment made with the same configurables
+ */
+fn ensure_configurables(
+    label: &str,
+    deployment: Option<&Deployment>,
+    configurables: &BTreeMap<String, String>,
+) -> Result<()> {
+    match deployment {
+        Some(deployment) if deployment.configurables != *configurables => Err(Error::InvalidManifest(format!(
+            "{} was deployed with {:?}, requested {:?}", label, deployment.configurables, configurables
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/*
+  Contract id of a recorded deployment, once checked that it was built from the
+  same binary and still exists on the node
+ */
+async fn ensure_deployed(deployment: &Deployment, binary: &str, wallet: &WalletUnlocked) -> Result<ContractId> {
+    let contract_id = deployment.contract_id()?;
+    let hash = bytecode_hash(binary)?;
+    if deployment.bytecode_hash != hash {
+        return Err(Error::InvalidManifest(format!(
+            "{} was deployed from bytecode {}, {} is {}", contract_id, deployment.bytecode_hash, binary, hash
+        )));
+    }
+    let exists = wallet
+        .try_provider()?
+        .contract_exists(&Bech32ContractId::from(contract_id))
+        .await?;
+    if !exists {
+        return Err(Error::NotDeployed(contract_id));
+    }
+    Ok(contract_id)
+}
@@ -0,0 +1,289 @@
+//! Off-chain reference model of `FuniSwapV2Pair`.
+//!
+//! `PairModel` follows `mint`, `burn` and `swap` of `funi_pair.sw` step by step
+//! with the same u256 arithmetic, so it can be run side by side with a deployed
+//! pair to catch any divergence in the Sway math.
+
+use std::fmt;
+
+use fuels::types::U256;
+
+use crate::abi::{
+    MathError,
+    PairError,
+};
+use crate::error::Error;
+
+pub const MINIMUM_LIQUIDITY: u64 = 1000;
+
+/// Reason a pair call reverts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PairRevert {
+    InsufficientLiquidity,
+    InsufficientLiquidityBurned,
+    InsufficientOutputAmount,
+    InsufficientInputAmount,
+    KInvariantIncorrect,
+    /// `to_u64` was given an amount above `u64::MAX`.
+    AmountTooHigh,
+    /// The result of `mul_div` is above `U256::MAX`.
+    Overflow,
+    /// `mul_div` was given a zero divisor.
+    DivisionByZero,
+    /// u256 overflow, underflow or division by zero, the VM panics.
+    Panic,
+}
+
+impl PairRevert {
+    /*
+      True when the call failed with the error the contract logs for this reason.
+      A VM panic logs nothing, any failure matches it.
+     */
+    pub fn matches(&self, e: &Error) -> bool {
+        match self {
+            PairRevert::InsufficientLiquidity => e.is_revert(PairError::InsufficientLiquidity),
+            PairRevert::InsufficientLiquidityBurned => e.is_revert(PairError::InsufficientLiquidityBurned),
+            PairRevert::InsufficientOutputAmount => e.is_revert(PairError::InsufficientOutputAmount),
+            PairRevert::InsufficientInputAmount => e.is_revert(PairError::InsufficientInputAmount),
+            PairRevert::KInvariantIncorrect => e.is_revert(PairError::KInvariantIncorrect),
+            PairRevert::AmountTooHigh => e.is_revert(MathError::AmountTooHigh),
+            PairRevert::Overflow => e.is_revert(MathError::Overflow),
+            PairRevert::DivisionByZero => e.is_revert(MathError::DivisionByZero),
+            PairRevert::Panic => true,
+        }
+    }
+}
+
+impl fmt::Display for PairRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PairRevert::Panic => write!(f, "vm panic"),
+            revert => write!(f, "{:?}", revert),
+        }
+    }
+}
+
+pub type ModelResult<T> = std::result::Result<T, PairRevert>;
+
+/// State of a pair: stored reserves and LP supply, plus the token balances
+/// the contract holds, which include deposits not yet minted or swapped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PairModel {
+    pub reserve0: U256,
+    pub reserve1: U256,
+    pub total_supply: U256,
+    pub balance0: U256,
+    pub balance1: U256,
+}
+
+impl PairModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /*
+      Transfer token0 / token1 to the pair
+     */
+    pub fn deposit(&mut self, amount0: u64, amount1: u64) {
+        self.balance0 += U256::from(amount0);
+        self.balance1 += U256::from(amount1);
+    }
+
+    pub fn get_reserves(&self) -> ModelResult<(u64, u64)> {
+        Ok((to_u64(self.reserve0)?, to_u64(self.reserve1)?))
+    }
+
+    /*
+      The state is only updated when the call succeeds, like a reverted transaction.
+     */
+    pub fn mint(&mut self) -> ModelResult<u64> {
+        let mut next = self.clone();
+        let liquidity = next.apply_mint()?;
+        *self = next;
+        Ok(liquidity)
+    }
+
+    pub fn burn(&mut self, liquidity: u64) -> ModelResult<(u64, u64)> {
+        let mut next = self.clone();
+        let amounts = next.apply_burn(liquidity)?;
+        *self = next;
+        Ok(amounts)
+    }
+
+    pub fn swap(&mut self, amount0_out: u64, amount1_out: u64) -> ModelResult<()> {
+        let mut next = self.clone();
+        next.apply_swap(amount0_out, amount1_out)?;
+        *self = next;
+        Ok(())
+    }
+
+    fn apply_mint(&mut self) -> ModelResult<u64> {
+        let total_supply = self.total_supply;
+        let amount0 = sub(self.balance0, self.reserve0)?;
+        let amount1 = sub(self.balance1, self.reserve1)?;
+
+        let liquidity = if total_supply.is_zero() {
+            let liquidity = sub(mul(amount0, amount1)?.integer_sqrt(), U256::from(MINIMUM_LIQUIDITY))?;
+            /* minted to the pair itself and locked forever */
+            self.total_supply = U256::from(MINIMUM_LIQUIDITY);
+            liquidity
+        } else {
+            std::cmp::min(
+                mul_div(amount0, total_supply, self.reserve0)?,
+                mul_div(amount1, total_supply, self.reserve1)?,
+            )
+        };
+        if liquidity.is_zero() {
+            return Err(PairRevert::InsufficientLiquidity);
+        }
+        self.total_supply = add(self.total_supply, liquidity)?;
+        let liquidity = to_u64(liquidity)?;
+        self.update();
+
+        Ok(liquidity)
+    }
+
+    fn apply_burn(&mut self, liquidity: u64) -> ModelResult<(u64, u64)> {
+        let total_supply = self.total_supply;
+        let liquidity = U256::from(liquidity);
+
+        let amount0 = mul_div(liquidity, self.balance0, total_supply)?;
+        let amount1 = mul_div(liquidity, self.balance1, total_supply)?;
+        if amount0.is_zero() || amount1.is_zero() {
+            return Err(PairRevert::InsufficientLiquidityBurned);
+        }
+        self.total_supply = sub(total_supply, liquidity)?;
+        let amount0 = to_u64(amount0)?;
+        let amount1 = to_u64(amount1)?;
+        self.balance0 = sub(self.balance0, U256::from(amount0))?;
+        self.balance1 = sub(self.balance1, U256::from(amount1))?;
+        self.update();
+
+        Ok((amount0, amount1))
+    }
+
+    fn apply_swap(&mut self, amount0_out: u64, amount1_out: u64) -> ModelResult<()> {
+        if amount0_out == 0 && amount1_out == 0 {
+            return Err(PairRevert::InsufficientOutputAmount);
+        }
+        let (reserve0, reserve1) = (self.reserve0, self.reserve1);
+        let amount0_out = U256::from(amount0_out);
+        let amount1_out = U256::from(amount1_out);
+        if !(amount0_out < reserve0 && amount1_out < reserve1) {
+            return Err(PairRevert::InsufficientLiquidity);
+        }
+
+        /* the contract can only send what it holds */
+        self.balance0 = sub(self.balance0, amount0_out)?;
+        self.balance1 = sub(self.balance1, amount1_out)?;
+        let (balance0, balance1) = (self.balance0, self.balance1);
+
+        let mut amount0_in = U256::zero();
+        let mut amount1_in = U256::zero();
+        if balance0 > sub(reserve0, amount0_out)? {
+            amount0_in = sub(balance0, sub(reserve0, amount0_out)?)?;
+        }
+        if balance1 > sub(reserve1, amount1_out)? {
+            amount1_in = sub(balance1, sub(reserve1, amount1_out)?)?;
+        }
+        if amount0_in.is_zero() && amount1_in.is_zero() {
+            return Err(PairRevert::InsufficientInputAmount);
+        }
+
+        let balance0_adjusted = sub(mul(balance0, U256::from(1000))?, mul(amount0_in, U256::from(3))?)?;
+        let balance1_adjusted = sub(mul(balance1, U256::from(1000))?, mul(amount1_in, U256::from(3))?)?;
+        let reserve0_scaled = mul(reserve0, U256::from(1000))?;
+        let reserve1_scaled = mul(reserve1, U256::from(1000))?;
+        if !mul_gte(balance0_adjusted, balance1_adjusted, reserve0_scaled, reserve1_scaled) {
+            return Err(PairRevert::KInvariantIncorrect);
+        }
+        self.update();
+
+        Ok(())
+    }
+
+    fn update(&mut self) {
+        self.reserve0 = self.balance0;
+        self.reserve1 = self.balance1;
+    }
+}
+
+/*
+ * ---- u256 arithmetic, panicking like the VM
+ */
+
+fn add(a: U256, b: U256) -> ModelResult<U256> {
+    a.checked_add(b).ok_or(PairRevert::Panic)
+}
+
+fn sub(a: U256, b: U256) -> ModelResult<U256> {
+    a.checked_sub(b).ok_or(PairRevert::Panic)
+}
+
+fn mul(a: U256, b: U256) -> ModelResult<U256> {
+    a.checked_mul(b).ok_or(PairRevert::Panic)
+}
+
+/*
+  a * b / c rounded down with a 512-bit product, same as mul_div of funi_lib
+ */
+pub fn mul_div(a: U256, b: U256, c: U256) -> ModelResult<U256> {
+    if c.is_zero() {
+        return Err(PairRevert::DivisionByZero);
+    }
+    if let Some(product) = a.checked_mul(b) {
+        return Ok(product / c);
+    }
+    let (hi, lo) = mul_wide(a, b);
+    if hi >= c {
+        return Err(PairRevert::Overflow);
+    }
+
+    let mut remainder = hi;
+    let mut quotient = U256::zero();
+    for i in (0..256).rev() {
+        let carry = remainder.bit(255);
+        remainder = (remainder << 1) | if lo.bit(i) { U256::one() } else { U256::zero() };
+        quotient = quotient << 1;
+        if carry || remainder >= c {
+            /* with the carry the remainder is 2^256 + remainder, the wrapping sub gives the difference */
+            remainder = remainder.overflowing_sub(c).0;
+            quotient = quotient | U256::one();
+        }
+    }
+    Ok(quotient)
+}
+
+/*
+  a * b as the (high, low) halves of the 512-bit product
+ */
+pub fn mul_wide(a: U256, b: U256) -> (U256, U256) {
+    let low_mask = U256::from(u128::MAX);
+    let (a1, a0) = (a >> 128, a & low_mask);
+    let (b1, b0) = (b >> 128, b & low_mask);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 128) + (p01 & low_mask) + (p10 & low_mask);
+    let lo = ((mid & low_mask) << 128) | (p00 & low_mask);
+    let hi = p11 + (p01 >> 128) + (p10 >> 128) + (mid >> 128);
+    (hi, lo)
+}
+
+/*
+  a * b >= c * d on the 512-bit products
+ */
+pub fn mul_gte(a: U256, b: U256, c: U256, d: U256) -> bool {
+    mul_wide(a, b) >= mul_wide(c, d)
+}
+
+/*
+  Same rule as `to_u64` of funi_lib: revert when above u64::MAX
+ */
+pub fn to_u64(amount: U256) -> ModelResult<u64> {
+    if amount > U256::from(u64::MAX) {
+        return Err(PairRevert::AmountTooHigh);
+    }
+    Ok(amount.as_u64())
+}
@@ -0,0 +1,409 @@
+use std::str::FromStr;
+
+use fuels::{
+    prelude::*,
+    types::ContractId,
+    types::{
+        tx_status::TxStatus,
+        AssetId,
+        Identity,
+        TxId,
+        U256,
+    }
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::abi::{
+    FuniSwapV2Pair,
+    FuniSwapV2PairConfigurables,
+    PairState,
+    SyncEvent,
+    FUNI_PAIR_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::{Error, Result};
+use crate::instance::{
+    CallLog,
+    ContractInstance,
+};
+use crate::utils::{
+    deploy_contract,
+    get_default_asset_id,
+};
+
+impl ContractInstance<FuniSwapV2Pair<WalletUnlocked>> {
+    /*
+      Deploy a new pair with default configurables
+     */
+    pub async fn deploy(wallet: WalletUnlocked, salt: Salt, tx_config: TxConfig) -> Result<Self> {
+        Self::deploy_with_configurables(wallet, salt, FuniSwapV2PairConfigurables::default(), tx_config).await
+    }
+
+    pub async fn deploy_with_configurables(
+        wallet: WalletUnlocked,
+        salt: Salt,
+        configurables: FuniSwapV2PairConfigurables,
+        tx_config: TxConfig,
+    ) -> Result<Self> {
+        let contract_id = deploy_contract(FUNI_PAIR_BINARY, &wallet, salt, configurables, tx_config).await?;
+        Ok(Self::attach(contract_id, wallet, tx_config))
+    }
+
+    /*
+      Wrap an already deployed pair
+     */
+    pub fn attach(contract_id: ContractId, wallet: WalletUnlocked, tx_config: TxConfig) -> Self {
+        Self {
+            instance: FuniSwapV2Pair::new(contract_id, wallet.clone()),
+            contract_id,
+            wallet,
+            tx_config,
+            call_log: CallLog::default(),
+        }
+    }
+
+    pub async fn get_contract_balance(&self, asset_id: AssetId) -> Result<u64> {
+        let assets = self.instance.clone().get_balances().await?;
+        Ok(assets.get(&asset_id).copied().unwrap_or(0))
+    }
+
+    pub fn get_instance(&self) -> FuniSwapV2Pair<WalletUnlocked> {
+        self.instance.clone()
+    }
+
+    /*
+      SyncEvent logged by the last call to this pair, if it updated the reserves.
+      The receipts of the call are fetched from the provider.
+     */
+    pub async fn last_sync_event(&self) -> Result<Option<SyncEvent>> {
+        let Some(tx_id) = self.last_call().and_then(|record| record.tx_id) else {
+            return Ok(None);
+        };
+        let tx_id = TxId::from_str(&tx_id).map_err(|e| Error::InvalidEvent(e.to_string()))?;
+        let provider = self.wallet.provider().ok_or(Error::NoProvider)?;
+        let TxStatus::Success { receipts } = provider.tx_status(&tx_id).await? else {
+            return Ok(None);
+        };
+        let mut events = self.instance.log_decoder().decode_logs_with_type::<SyncEvent>(&receipts)?;
+        Ok(events.pop())
+    }
+
+    /*
+      call total_supply() function with the LP asset id.
+     */
+    pub async fn call_total_supply(self) -> Result<u64> {
+        let asset_id = get_default_asset_id(self.contract_id);
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .total_supply(asset_id)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        self.record("total_supply", result)
+            .ok_or(Error::UnknownAsset(asset_id))
+    }
+
+    /*
+      call get_reserves() function.
+     */
+    pub async fn call_get_reserves(self) -> Result<(u64, u64)> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_reserves()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("get_reserves", result))
+    }
+
+    /*
+      call mint() function.
+     */
+    pub async fn call_mint(self, to: Identity) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .mint(to)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("mint", result))
+    }
+
+    /*
+      call burn() function with `amount` LP tokens, all of them are burnt.
+     */
+    pub async fn call_burn(self, to: Identity, amount: u64) -> Result<(u64, u64)> {
+        let asset_id = get_default_asset_id(self.contract_id);
+        self.call_burn_with(to, asset_id, amount, None).await
+    }
+
+    /*
+      call burn() function sending `amount` of asset_id. With Some(liquidity) only that much
+      is burnt and the rest is sent back to the wallet.
+     */
+    pub async fn call_burn_with(
+        self,
+        to: Identity,
+        asset_id: AssetId,
+        amount: u64,
+        liquidity: Option<u64>,
+    ) -> Result<(u64, u64)> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .burn(to, liquidity)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(3))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount,
+            asset_id,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("burn", result))
+    }
+
+    /*
+      call token0() function.
+     */
+    pub async fn call_token0(self) -> Result<AssetId> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .token0()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("token0", result))
+    }
+
+    /*
+      call token1() function.
+     */
+    pub async fn call_token1(self) -> Result<AssetId> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .token1()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("token1", result))
+    }
+
+    /*
+      call minimum_liquidity() function.
+     */
+    pub async fn call_minimum_liquidity(self) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .minimum_liquidity()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("minimum_liquidity", result))
+    }
+
+    /*
+      call factory() function. Zero when the pair was deployed without a factory.
+     */
+    pub async fn call_factory(self) -> Result<ContractId> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .factory()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("factory", result))
+    }
+
+    /*
+      call stable() function, false for this pair.
+     */
+    pub async fn call_stable(self) -> Result<bool> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .stable()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("stable", result))
+    }
+
+    /*
+      call get_pair_state() function, mapped to a serialisable PairStateView.
+     */
+    pub async fn call_get_pair_state(self) -> Result<PairStateView> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_pair_state()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        let contract_id = self.contract_id;
+        Ok(PairStateView::new(contract_id, self.record("get_pair_state", result)))
+    }
+
+    /*
+      call get_underlying() function: amounts `liquidity` LP tokens can be burnt for.
+     */
+    pub async fn call_get_underlying(self, liquidity: u64) -> Result<(u64, u64)> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_underlying(liquidity)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("get_underlying", result))
+    }
+
+    /*
+      call swap() function.
+     */
+    pub async fn call_swap(self, amount0_out: u64, amount1_out: u64, to: Identity) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .swap(amount0_out, amount1_out, to)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+        self.record("swap", result);
+        Ok(())
+    }
+}
+
+/// State of a pair as returned by `get_pair_state()`, with the ids as hex strings so
+/// that it can be serialised for frontends. The u256 values are kept as U256 and
+/// serialised as decimal strings.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PairStateView {
+    pub pair_id: String,
+    pub lp_asset_id: String,
+    pub token0: String,
+    pub token1: String,
+    pub reserve0: u64,
+    pub reserve1: u64,
+    /// LP supply, including the locked MINIMUM_LIQUIDITY.
+    #[serde(with = "u256_string")]
+    pub total_supply: U256,
+    pub fee_bps: u64,
+    /// Invariant of the current reserves, reserve0 * reserve1 for the pair. This is
+    /// not UniswapV2's kLast, the pairs take no protocol fee and don't track it.
+    #[serde(with = "u256_string")]
+    pub k: U256,
+}
+
+impl PairStateView {
+    pub fn new(pair: ContractId, state: PairState) -> Self {
+        Self {
+            pair_id: pair.to_string(),
+            lp_asset_id: get_default_asset_id(pair).to_string(),
+            token0: state.token0.to_string(),
+            token1: state.token1.to_string(),
+            reserve0: state.reserve0,
+            reserve1: state.reserve1,
+            total_supply: state.total_supply,
+            fee_bps: state.fee_bps,
+            k: state.k,
+        }
+    }
+}
+
+/*
+  U256 as a decimal string, JSON numbers can't hold it
+ */
+mod u256_string {
+    use fuels::types::U256;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<U256, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        U256::from_dec_str(&value).map_err(D::Error::custom)
+    }
+}
+
+/*
+  Configurables of the pair for two tokens, in any order. The pair requires
+  token0 < token1, so they are sorted first.
+ */
+pub fn create_funi_pair_configurables(token_a: AssetId, token_b: AssetId) -> Result<FuniSwapV2PairConfigurables> {
+    let (token0, token1) = sort_tokens(token_a, token_b);
+    Ok(FuniSwapV2PairConfigurables::default()
+    .with_token0(token0)?
+    .with_token1(token1)?)
+}
+
+/*
+  Configurables of a pair of a token and the base asset of the chain. The pair
+  is allowed to hold the base asset even when the chain's base asset is zero.
+ */
+pub fn create_funi_pair_base_configurables(token: AssetId, base_asset_id: AssetId) -> Result<FuniSwapV2PairConfigurables> {
+    Ok(create_funi_pair_configurables(token, base_asset_id)?
+    .with_allow_base(true)?)
+}
+
+/*
+  Same order as _sort_tokens() of the factory
+ */
+pub fn sort_tokens(token_a: AssetId, token_b: AssetId) -> (AssetId, AssetId) {
+    if token_a > token_b {
+        (token_b, token_a)
+    } else {
+        (token_a, token_b)
+    }
+}
+
+/*
+  Output amount of a swap for the given input, after the 0.3% fee.
+  Same formula as UniswapV2Library.getAmountOut(). None when nothing is
+  swapped into an empty reserve, where the contract reverts instead.
+ */
+pub fn get_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Option<u64> {
+    let amount_in_with_fee = U256::from(amount_in) * 997;
+    let numerator = amount_in_with_fee * U256::from(reserve_out);
+    let denominator = U256::from(reserve_in) * 1000 + amount_in_with_fee;
+    if denominator.is_zero() {
+        return None;
+    }
+    u64::try_from(numerator / denominator).ok()
+}
+
+/*
+  Part of amount_in to swap before adding single-sided liquidity, so that the
+  rest and the swap output match the reserve ratio after the swap (0.3% fee).
+  Same formula as _zap_swap_amount() of the router.
+ */
+pub fn zap_swap_amount(amount_in: u64, reserve_in: u64) -> u64 {
+    let amount_in = U256::from(amount_in);
+    let reserve_in = U256::from(reserve_in);
+    let root = (reserve_in * (reserve_in * 3988009 + amount_in * 3988000)).integer_sqrt();
+    ((root - reserve_in * 1997) / 1994).as_u64()
+}
@@ -0,0 +1,94 @@
+//! Value of an LP position.
+//!
+//! A [`Position`] is a snapshot of an LP balance with the amounts it can be
+//! burnt for, as given by the pair's `get_underlying()`, and the reserves at
+//! that time. Comparing it to a snapshot taken when the liquidity was added
+//! gives the impermanent loss of the position.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::instance::GenericMethods;
+use crate::Pair;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    pub liquidity: u64,
+    /// LP supply, including the locked MINIMUM_LIQUIDITY.
+    pub total_supply: u128,
+    /// Amounts the LP balance can be burnt for.
+    pub amount0: u64,
+    pub amount1: u64,
+    pub reserve0: u64,
+    pub reserve1: u64,
+}
+
+impl Position {
+    /*
+      Share of the pool, in percent
+     */
+    pub fn share(&self) -> f64 {
+        if self.total_supply == 0 {
+            return 0.0;
+        }
+        self.liquidity as f64 / self.total_supply as f64 * 100.0
+    }
+
+    /*
+      Price of token0 in token1 given by the reserves, None for an empty pool
+     */
+    pub fn price(&self) -> Option<f64> {
+        if self.reserve0 == 0 {
+            return None;
+        }
+        Some(self.reserve1 as f64 / self.reserve0 as f64)
+    }
+
+    /*
+      Value of the position, in token1. None for an empty pool
+     */
+    pub fn value(&self) -> Option<f64> {
+        Some(self.amount0 as f64 * self.price()? + self.amount1 as f64)
+    }
+
+    /*
+      Value of the position against holding the amounts of the entry snapshot
+      instead, both at the current price. Negative for a loss, e.g. -0.05 is 5%.
+      Swap fees earned since the entry are included. The entry has to be taken
+      for the same LP balance. None when the pool or the entry is empty.
+     */
+    pub fn impermanent_loss(&self, entry: &Position) -> Option<f64> {
+        let held = entry.amount0 as f64 * self.price()? + entry.amount1 as f64;
+        if held == 0.0 {
+            return None;
+        }
+        Some(self.value()? / held - 1.0)
+    }
+}
+
+impl Pair {
+    /*
+      Position of `liquidity` LP tokens at the current reserves
+     */
+    pub async fn position(&self, liquidity: u64) -> Result<Position> {
+        let state = self.clone().call_get_pair_state().await?;
+        let (amount0, amount1) = self.clone().call_get_underlying(liquidity).await?;
+        Ok(Position {
+            liquidity,
+            total_supply: u128::try_from(state.total_supply)
+                .map_err(|_| Error::Overflow(format!("total_supply {} above u128::MAX", state.total_supply)))?,
+            amount0,
+            amount1,
+            reserve0: state.reserve0,
+            reserve1: state.reserve1,
+        })
+    }
+
+    /*
+      Position of the LP balance of the client wallet
+     */
+    pub async fn wallet_position(&self) -> Result<Position> {
+        let liquidity = self.deployer_balance(self.get_default_asset_id()).await?;
+        self.position(liquidity).await
+    }
+}
@@ -0,0 +1,329 @@
+use fuels::{
+    prelude::*,
+    types::ContractId,
+    types::{
+        AssetId,
+        Identity,
+    }
+};
+
+use crate::abi::{
+    FuniSwapV2Router02,
+    FuniSwapV2Router02Configurables,
+    FUNI_ROUTER02_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::{Error, Result};
+use crate::instance::{
+    CallLog,
+    ContractInstance,
+};
+use crate::utils::{
+    deploy_contract,
+    get_default_asset_id,
+};
+
+impl ContractInstance<FuniSwapV2Router02<WalletUnlocked>> {
+    /*
+      Deploy a new router with default configurables
+     */
+    pub async fn deploy(wallet: WalletUnlocked, salt: Salt, tx_config: TxConfig) -> Result<Self> {
+        Self::deploy_with_configurables(wallet, salt, FuniSwapV2Router02Configurables::default(), tx_config).await
+    }
+
+    pub async fn deploy_with_configurables(
+        wallet: WalletUnlocked,
+        salt: Salt,
+        configurables: FuniSwapV2Router02Configurables,
+        tx_config: TxConfig,
+    ) -> Result<Self> {
+        let contract_id = deploy_contract(FUNI_ROUTER02_BINARY, &wallet, salt, configurables, tx_config).await?;
+        Ok(Self::attach(contract_id, wallet, tx_config))
+    }
+
+    /*
+      Wrap an already deployed router
+     */
+    pub fn attach(contract_id: ContractId, wallet: WalletUnlocked, tx_config: TxConfig) -> Self {
+        Self {
+            instance: FuniSwapV2Router02::new(contract_id, wallet.clone()),
+            contract_id,
+            wallet,
+            tx_config,
+            call_log: CallLog::default(),
+        }
+    }
+
+    /*
+      call deposit() function.
+     */
+    pub async fn call_deposit(self, to: Identity, asset: AssetId, amount: u64) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .deposit(to)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount,
+            asset,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+        self.record("deposit", result);
+        Ok(())
+    }
+
+    /*
+      call get_deposits() function.
+     */
+    pub async fn call_get_deposits(self, depositor: Identity) -> Result<(u64, u64)> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_deposits(depositor)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("get_deposits", result))
+    }
+
+    /*
+      call withdraw() function.
+     */
+    pub async fn call_withdraw(self, to: Identity) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .withdraw(to)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+        self.record("withdraw", result);
+        Ok(())
+    }
+
+    /*
+      call add_liquidity_eth() function: amount_eth of the base asset is forwarded,
+      the token is taken from the deposits of the wallet.
+      Returns (amount_token, amount_eth, liquidity).
+     */
+    pub async fn call_add_liquidity_eth(
+        self,
+        pair: ContractId,
+        amount_eth: u64,
+        amount_token_desired: u64,
+        amount_token_min: u64,
+        amount_eth_min: u64,
+        to: Identity,
+    ) -> Result<(u64, u64, u64)> {
+        let base_asset_id = self.base_asset_id()?;
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .add_liquidity_eth(amount_token_desired, amount_token_min, amount_eth_min, to)
+        .with_contract_ids(&[pair.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount_eth,
+            base_asset_id,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("add_liquidity_eth", result))
+    }
+
+    /*
+      call swap_exact_eth_for_tokens() function with amount_in of the base asset.
+     */
+    pub async fn call_swap_exact_eth_for_tokens(
+        self,
+        pair: ContractId,
+        amount_in: u64,
+        amount_out_min: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let base_asset_id = self.base_asset_id()?;
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .swap_exact_eth_for_tokens(amount_out_min, to)
+        .with_contract_ids(&[pair.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount_in,
+            base_asset_id,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("swap_exact_eth_for_tokens", result))
+    }
+
+    /*
+      call swap_exact_tokens_for_eth() function with amount_in of token.
+     */
+    pub async fn call_swap_exact_tokens_for_eth(
+        self,
+        pair: ContractId,
+        token: AssetId,
+        amount_in: u64,
+        amount_out_min: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .swap_exact_tokens_for_eth(amount_out_min, to)
+        .with_contract_ids(&[pair.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount_in,
+            token,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("swap_exact_tokens_for_eth", result))
+    }
+
+    /*
+      call zap_in() function with amount_in of asset_in, one of the pair tokens.
+     */
+    pub async fn call_zap_in(
+        self,
+        pair: ContractId,
+        asset_in: AssetId,
+        amount_in: u64,
+        min_lp_out: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .zap_in(min_lp_out, to)
+        .with_contract_ids(&[pair.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount_in,
+            asset_in,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("zap_in", result))
+    }
+
+    /*
+      call zap_out() function with `liquidity` LP tokens of the pair.
+     */
+    pub async fn call_zap_out(
+        self,
+        pair: ContractId,
+        liquidity: u64,
+        asset_out: AssetId,
+        min_amount_out: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .zap_out(asset_out, min_amount_out, to)
+        .with_contract_ids(&[pair.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            liquidity,
+            get_default_asset_id(pair),
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("zap_out", result))
+    }
+
+    /*
+      call swap_exact_tokens_for_tokens_supporting_fee_on_transfer() function,
+      forwarding amount_in of the default asset of the taxed `token`, which the
+      router sends to the pair through the token's transfer().
+     */
+    pub async fn call_swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        self,
+        pair: ContractId,
+        token: ContractId,
+        amount_in: u64,
+        amount_out_min: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let asset_in = get_default_asset_id(token);
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .swap_exact_tokens_for_tokens_supporting_fee_on_transfer(token, amount_out_min, to)
+        .with_contract_ids(&[pair.into(), token.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount_in,
+            asset_in,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("swap_exact_tokens_for_tokens_supporting_fee_on_transfer", result))
+    }
+
+    /*
+      base asset of the chain the wallet is connected to
+     */
+    pub fn base_asset_id(&self) -> Result<AssetId> {
+        Ok(*self.wallet.provider().ok_or(Error::NoProvider)?.base_asset_id())
+    }
+
+    pub async fn get_contract_balance(&self, asset_id: AssetId) -> Result<u64> {
+        let assets = self.instance.clone().get_balances().await?;
+        Ok(assets.get(&asset_id).copied().unwrap_or(0))
+    }
+}
+
+pub fn create_funi_router02_configurables(token0: AssetId, token1: AssetId) -> Result<FuniSwapV2Router02Configurables> {
+    Ok(FuniSwapV2Router02Configurables::default()
+    .with_token0(token0)?
+    .with_token1(token1)?)
+}
+
+/*
+  Configurables of a router for the pair of token_a and token_b
+ */
+pub fn create_funi_router02_pair_configurables(
+    token_a: AssetId,
+    token_b: AssetId,
+    pair: ContractId,
+) -> Result<FuniSwapV2Router02Configurables> {
+    Ok(create_funi_router02_configurables(token_a, token_b)?
+    .with_pair(pair)?)
+}
+
+/*
+  Configurables of a router for the pair of a token and the base asset
+ */
+pub fn create_funi_router02_eth_configurables(
+    token: AssetId,
+    base_asset_id: AssetId,
+    pair: ContractId,
+) -> Result<FuniSwapV2Router02Configurables> {
+    create_funi_router02_pair_configurables(token, base_asset_id, pair)
+}
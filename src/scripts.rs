@@ -0,0 +1,93 @@
+//! Sway scripts run against a pair, so that depositing the assets and calling the
+//! pair happen in the same transaction.
+
+use fuels::{
+    prelude::*,
+    types::{
+        input::Input,
+        output::Output,
+        AssetId,
+        Identity,
+    }
+};
+
+use crate::abi::{
+    FuniSwapV2AddLiquidityScript,
+    FuniSwapV2Pair,
+    FuniSwapV2SwapScript,
+    FUNI_ADD_LIQUIDITY_SCRIPT_BINARY,
+    FUNI_SWAP_SCRIPT_BINARY,
+};
+use crate::error::Result;
+use crate::instance::ContractInstance;
+
+impl ContractInstance<FuniSwapV2Pair<WalletUnlocked>> {
+    /*
+      run FuniSwapV2AddLiquidityScript: transfer amount_a of asset_a and amount_b of asset_b
+      to the pair and mint() in one transaction. Fails below min_liquidity.
+     */
+    pub async fn call_add_liquidity_script(
+        self,
+        asset_a: AssetId,
+        amount_a: u64,
+        asset_b: AssetId,
+        amount_b: u64,
+        min_liquidity: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let (inputs, outputs) = script_coins(&self.wallet, &[(asset_a, amount_a), (asset_b, amount_b)]).await?;
+        let result = FuniSwapV2AddLiquidityScript::new(self.wallet.clone(), FUNI_ADD_LIQUIDITY_SCRIPT_BINARY)
+        .main(self.contract_id, asset_a, amount_a, asset_b, amount_b, min_liquidity, to)
+        .with_inputs(inputs)
+        .with_outputs(outputs)
+        .with_contract_ids(&[self.contract_id.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("add_liquidity_script", result))
+    }
+
+    /*
+      run FuniSwapV2SwapScript: transfer amount_in of asset_in to the pair and swap it
+      in one transaction. Fails when the output is below amount_out_min.
+     */
+    pub async fn call_swap_script(
+        self,
+        asset_in: AssetId,
+        amount_in: u64,
+        amount_out_min: u64,
+        to: Identity,
+    ) -> Result<u64> {
+        let (inputs, outputs) = script_coins(&self.wallet, &[(asset_in, amount_in)]).await?;
+        let result = FuniSwapV2SwapScript::new(self.wallet.clone(), FUNI_SWAP_SCRIPT_BINARY)
+        .main(self.contract_id, asset_in, amount_in, amount_out_min, to)
+        .with_inputs(inputs)
+        .with_outputs(outputs)
+        .with_contract_ids(&[self.contract_id.into()])
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("swap_script", result))
+    }
+}
+
+/*
+  Coin inputs of the wallet covering each amount, with the change going back to the wallet.
+  The fee is added on top by the transaction builder.
+ */
+pub(crate) async fn script_coins(
+    wallet: &WalletUnlocked,
+    amounts: &[(AssetId, u64)],
+) -> Result<(Vec<Input>, Vec<Output>)> {
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    for &(asset_id, amount) in amounts {
+        inputs.extend(wallet.get_asset_inputs_for_amount(asset_id, amount, None).await?);
+        outputs.push(Output::change(wallet.address().into(), 0, asset_id));
+    }
+    Ok((inputs, outputs))
+}
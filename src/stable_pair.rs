@@ -0,0 +1,237 @@
+use fuels::{
+    prelude::*,
+    types::ContractId,
+    types::{
+        AssetId,
+        Identity,
+    }
+};
+
+use crate::abi::{
+    FuniSwapV2StablePair,
+    FuniSwapV2StablePairConfigurables,
+    FUNI_STABLE_PAIR_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::{Error, Result};
+use crate::instance::{
+    CallLog,
+    ContractInstance,
+    GenericMethods,
+};
+use crate::utils::{
+    deploy_contract,
+    get_default_asset_id,
+};
+use crate::Token;
+
+impl ContractInstance<FuniSwapV2StablePair<WalletUnlocked>> {
+    /*
+      Deploy a new stable pair with default configurables
+     */
+    pub async fn deploy(wallet: WalletUnlocked, salt: Salt, tx_config: TxConfig) -> Result<Self> {
+        Self::deploy_with_configurables(wallet, salt, FuniSwapV2StablePairConfigurables::default(), tx_config).await
+    }
+
+    pub async fn deploy_with_configurables(
+        wallet: WalletUnlocked,
+        salt: Salt,
+        configurables: FuniSwapV2StablePairConfigurables,
+        tx_config: TxConfig,
+    ) -> Result<Self> {
+        let contract_id = deploy_contract(FUNI_STABLE_PAIR_BINARY, &wallet, salt, configurables, tx_config).await?;
+        Ok(Self::attach(contract_id, wallet, tx_config))
+    }
+
+    /*
+      Wrap an already deployed stable pair
+     */
+    pub fn attach(contract_id: ContractId, wallet: WalletUnlocked, tx_config: TxConfig) -> Self {
+        Self {
+            instance: FuniSwapV2StablePair::new(contract_id, wallet.clone()),
+            contract_id,
+            wallet,
+            tx_config,
+            call_log: CallLog::default(),
+        }
+    }
+
+    pub async fn get_contract_balance(&self, asset_id: AssetId) -> Result<u64> {
+        let assets = self.instance.clone().get_balances().await?;
+        Ok(assets.get(&asset_id).copied().unwrap_or(0))
+    }
+
+    /*
+      call total_supply() function with the LP asset id.
+     */
+    pub async fn call_total_supply(self) -> Result<u64> {
+        let asset_id = get_default_asset_id(self.contract_id);
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .total_supply(asset_id)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        self.record("total_supply", result)
+            .ok_or(Error::UnknownAsset(asset_id))
+    }
+
+    /*
+      call get_reserves() function.
+     */
+    pub async fn call_get_reserves(self) -> Result<(u64, u64)> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_reserves()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("get_reserves", result))
+    }
+
+    /*
+      call stable() function, true for this pair.
+     */
+    pub async fn call_stable(self) -> Result<bool> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .stable()
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("stable", result))
+    }
+
+    /*
+      call get_amount_out() function: output of a swap of amount_in of asset_in, after the fee.
+     */
+    pub async fn call_get_amount_out(self, amount_in: u64, asset_in: AssetId) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .get_amount_out(amount_in, asset_in)
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("get_amount_out", result))
+    }
+
+    /*
+      call mint() function.
+     */
+    pub async fn call_mint(self, to: Identity) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .mint(to)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("mint", result))
+    }
+
+    /*
+      call burn() function with `amount` LP tokens, all of them are burnt.
+     */
+    pub async fn call_burn(self, to: Identity, amount: u64) -> Result<(u64, u64)> {
+        let asset_id = get_default_asset_id(self.contract_id);
+        self.call_burn_with(to, asset_id, amount, None).await
+    }
+
+    /*
+      call burn() function sending `amount` of asset_id. With Some(liquidity) only that much
+      is burnt and the rest is sent back to the wallet.
+     */
+    pub async fn call_burn_with(
+        self,
+        to: Identity,
+        asset_id: AssetId,
+        amount: u64,
+        liquidity: Option<u64>,
+    ) -> Result<(u64, u64)> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .burn(to, liquidity)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(3))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount,
+            asset_id,
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("burn", result))
+    }
+
+    /*
+      call swap() function.
+     */
+    pub async fn call_swap(self, amount0_out: u64, amount1_out: u64, to: Identity) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .swap(amount0_out, amount1_out, to)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+        self.record("swap", result);
+        Ok(())
+    }
+}
+
+/*
+  Configurables of the stable pair for two tokens and their decimals, in any
+  order. The tokens are sorted like create_funi_pair_configurables() does.
+  The decimals have to be the SRC20 decimals of the tokens, prefer
+  stable_pair_configurables_for() which reads them.
+ */
+pub fn create_funi_stable_pair_configurables(
+    token_a: AssetId,
+    decimals_a: u8,
+    token_b: AssetId,
+    decimals_b: u8,
+) -> Result<FuniSwapV2StablePairConfigurables> {
+    let ((token0, decimals0), (token1, decimals1)) = if token_a > token_b {
+        ((token_b, decimals_b), (token_a, decimals_a))
+    } else {
+        ((token_a, decimals_a), (token_b, decimals_b))
+    };
+    Ok(FuniSwapV2StablePairConfigurables::default()
+    .with_token0(token0)?
+    .with_token1(token1)?
+    .with_decimals0(decimals0)?
+    .with_decimals1(decimals1)?)
+}
+
+/*
+  Same as create_funi_stable_pair_configurables(), with the decimals read from the SRC20 tokens.
+  A token without decimals for its asset is rejected.
+ */
+pub async fn stable_pair_configurables_for(
+    token_a: &Token,
+    token_b: &Token,
+) -> Result<FuniSwapV2StablePairConfigurables> {
+    let decimals_a = token_a.clone().call_decimals_def_asset_id().await?
+        .ok_or(Error::UnknownAsset(token_a.get_default_asset_id()))?;
+    let decimals_b = token_b.clone().call_decimals_def_asset_id().await?
+        .ok_or(Error::UnknownAsset(token_b.get_default_asset_id()))?;
+    create_funi_stable_pair_configurables(
+        token_a.get_default_asset_id(),
+        decimals_a,
+        token_b.get_default_asset_id(),
+        decimals_b,
+    )
+}
@@ -0,0 +1,116 @@
+use fuels::{
+    prelude::*,
+    types::ContractId,
+    types::{
+        Bits256,
+        Identity,
+    }
+};
+
+use crate::abi::{
+    SRC20Taxed,
+    SRC20TaxedConfigurables,
+    SRC20_TAXED_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::Result;
+use crate::instance::{
+    CallLog,
+    ContractInstance,
+};
+use crate::utils::{
+    deploy_contract,
+    get_default_asset_id,
+};
+
+impl ContractInstance<SRC20Taxed<WalletUnlocked>> {
+    /*
+      Deploy a new taxed token with default configurables
+     */
+    pub async fn deploy(wallet: WalletUnlocked, salt: Salt, tx_config: TxConfig) -> Result<Self> {
+        Self::deploy_with_configurables(wallet, salt, SRC20TaxedConfigurables::default(), tx_config).await
+    }
+
+    pub async fn deploy_with_configurables(
+        wallet: WalletUnlocked,
+        salt: Salt,
+        configurables: SRC20TaxedConfigurables,
+        tx_config: TxConfig,
+    ) -> Result<Self> {
+        let contract_id = deploy_contract(SRC20_TAXED_BINARY, &wallet, salt, configurables, tx_config).await?;
+        Ok(Self::attach(contract_id, wallet, tx_config))
+    }
+
+    /*
+      Wrap an already deployed taxed token
+     */
+    pub fn attach(contract_id: ContractId, wallet: WalletUnlocked, tx_config: TxConfig) -> Self {
+        Self {
+            instance: SRC20Taxed::new(contract_id, wallet.clone()),
+            contract_id,
+            wallet,
+            tx_config,
+            call_log: CallLog::default(),
+        }
+    }
+
+    /*
+      call mint() function.
+     */
+    pub async fn call_mint(self, recipient: Identity, sub_id: Bits256, amount: u64) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .mint(recipient, sub_id, amount)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+        self.record("mint", result);
+        Ok(())
+    }
+
+    /*
+      call transfer() function: send amount through the token, minus the fee.
+      Returns the amount received by `to`.
+     */
+    pub async fn call_transfer(self, to: Identity, amount: u64) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .transfer(to)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call_params(CallParameters::new(
+            amount,
+            get_default_asset_id(self.contract_id),
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+
+        Ok(self.record("transfer", result))
+    }
+
+    /*
+      call rebase() function: grow the balance of holder. Returns the amount minted.
+     */
+    pub async fn call_rebase(self, holder: ContractId) -> Result<u64> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .rebase(holder)
+        .with_contract_ids(&[holder.into()])
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("rebase", result))
+    }
+}
+
+pub fn create_src20_taxed_configurables(fee_bps: u64, rebase_bps: u64) -> Result<SRC20TaxedConfigurables> {
+    Ok(SRC20TaxedConfigurables::default()
+    .with_fee_bps(fee_bps)?
+    .with_rebase_bps(rebase_bps)?)
+}
@@ -0,0 +1,223 @@
+use fuels::{
+    prelude::*,
+    types::ContractId,
+    types::{
+        AssetId,
+        Bits256,
+        Identity,
+        SizedAsciiString,
+    }
+};
+
+use crate::abi::{
+    SRC20,
+    SRC20Configurables,
+    SRC20_BINARY,
+};
+use crate::config::TxConfig;
+use crate::error::{Error, Result};
+use crate::instance::{
+    CallLog,
+    ContractInstance,
+};
+use crate::utils::{
+    deploy_contract,
+    get_default_asset_id,
+    random_salt,
+    DEFAULT_SUB_ID,
+};
+
+impl ContractInstance<SRC20<WalletUnlocked>> {
+    /*
+      Deploy a new SRC20 token with default configurables
+     */
+    pub async fn deploy(wallet: WalletUnlocked, salt: Salt, tx_config: TxConfig) -> Result<Self> {
+        Self::deploy_with_configurables(wallet, salt, SRC20Configurables::default(), tx_config).await
+    }
+
+    pub async fn deploy_with_configurables(
+        wallet: WalletUnlocked,
+        salt: Salt,
+        configurables: SRC20Configurables,
+        tx_config: TxConfig,
+    ) -> Result<Self> {
+        let contract_id = deploy_contract(SRC20_BINARY, &wallet, salt, configurables, tx_config).await?;
+        Ok(Self::attach(contract_id, wallet, tx_config))
+    }
+
+    /*
+      Deploy two tokens with random salts, sorted by asset id, so the first
+      one is the token0 of their pair
+     */
+    pub async fn deploy_sorted(wallet: WalletUnlocked, tx_config: TxConfig) -> Result<(Self, Self)> {
+        let token_a = Self::deploy(wallet.clone(), random_salt(), tx_config).await?;
+        let token_b = Self::deploy(wallet, random_salt(), tx_config).await?;
+        if get_default_asset_id(token_a.contract_id) > get_default_asset_id(token_b.contract_id) {
+            Ok((token_b, token_a))
+        } else {
+            Ok((token_a, token_b))
+        }
+    }
+
+    /*
+      Wrap an already deployed SRC20 token
+     */
+    pub fn attach(contract_id: ContractId, wallet: WalletUnlocked, tx_config: TxConfig) -> Self {
+        Self {
+            instance: SRC20::new(contract_id, wallet.clone()),
+            contract_id,
+            wallet,
+            tx_config,
+            call_log: CallLog::default(),
+        }
+    }
+
+    /*
+      Call name() function and return result
+     */
+    pub async fn call_name(self, asset_id: AssetId) -> Result<Option<String>> {
+        let result = self.instance.clone()
+            .with_account(self.wallet.clone())
+            .methods()
+            .name(asset_id) // smart contract function
+            .with_tx_policies(self.tx_config.tx_policies())
+            .call()
+            .await?;
+
+        Ok(self.record("name", result))
+    }
+
+    /*
+      Call name() function with default Asset ID and return result
+     */
+    pub async fn call_name_def_asset_id(self) -> Result<Option<String>> {
+        self.clone().call_name(
+            get_default_asset_id(self.contract_id)
+        ).await
+    }
+
+    /*
+      Call symbol() function and return result
+     */
+    pub async fn call_symbol(self, asset_id: AssetId) -> Result<Option<String>> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .symbol(asset_id) // smart contract function
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("symbol", result))
+    }
+
+    /*
+      Call symbol() function with default Asset ID and return result
+     */
+    pub async fn call_symbol_def_asset_id(self) -> Result<Option<String>> {
+        self.clone().call_symbol(
+            get_default_asset_id(self.contract_id)
+        ).await
+    }
+
+    /*
+      Call decimals() function and return result
+     */
+    pub async fn call_decimals(self, asset_id: AssetId) -> Result<Option<u8>> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .decimals(asset_id) // smart contract function
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+
+        Ok(self.record("decimals", result))
+    }
+
+    /*
+      Call decimals() function with default Asset ID and return result
+     */
+    pub async fn call_decimals_def_asset_id(self) -> Result<Option<u8>> {
+        self.clone().call_decimals(
+            get_default_asset_id(self.contract_id)
+        ).await
+    }
+
+    /*
+      call mint() function.
+     */
+    pub async fn call_mint(self, recipient: Identity, sub_id: Bits256, amount: u64) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())
+        .methods()
+        .mint(recipient, sub_id, amount)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+        .with_tx_policies(self.tx_config.tx_policies())
+        .call()
+        .await?;
+        self.record("mint", result);
+        Ok(())
+    }
+
+    pub async fn call_mint_default(self, recipient: Identity, amount: u64) -> Result<()> {
+        self.call_mint(recipient, DEFAULT_SUB_ID, amount).await
+    }
+
+    /*
+      call burn() function.
+     */
+    pub async fn call_burn(self, sub_id: Bits256, amount: u64) -> Result<()> {
+        let result = self.instance.clone()
+        .with_account(self.wallet.clone())           // <- called by
+        .methods()
+        .burn(sub_id, amount)                        // <- actual burn()
+        .with_tx_policies(self.tx_config.tx_policies()) // setup gas so that it doesn't revert
+        .call_params(CallParameters::new(            // transfer coins so that they can be burn
+            amount,
+            get_default_asset_id(self.contract_id),
+            self.tx_config.gas_limit,
+        ))?
+        .call()
+        .await?;
+        self.record("burn", result);
+        Ok(())
+    }
+
+    /*
+        call total_supply()
+     */
+    pub async fn call_total_supply(self, asset_id: AssetId) -> Result<Option<u64>> {
+        let result = self.instance.clone()
+            .with_account(self.wallet.clone())
+            .methods()
+            .total_supply(asset_id)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(2))
+            .with_tx_policies(self.tx_config.tx_policies())
+            .call()
+            .await?;
+
+        Ok(self.record("total_supply", result))
+    }
+
+    /*
+        call total_supply() with default asset id.
+        The token always reports a supply for its default asset id.
+     */
+    pub async fn call_total_supply_def_asset_id(self) -> Result<u64> {
+        let asset_id = get_default_asset_id(self.contract_id);
+        self.clone().call_total_supply(asset_id)
+            .await?
+            .ok_or(Error::UnknownAsset(asset_id))
+    }
+}
+
+pub fn create_src20_configurables(name: &str, symbol: &str, decimals: u8) -> Result<SRC20Configurables> {
+    let name_configurable: SizedAsciiString<5> = name.try_into()?;
+    let symbol_configurable: SizedAsciiString<3> = symbol.try_into()?;
+
+    Ok(SRC20Configurables::default()
+    .with_name(name_configurable)?
+    .with_symbol(symbol_configurable)?
+    .with_decimals(decimals)?)
+}
@@ -0,0 +1,106 @@
+use fuels::{
+    prelude::*,
+    core::Configurables,
+    types::ContractId,
+    types::{
+        AssetId,
+        Bytes32,
+        Bits256,
+    }
+};
+
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::config::TxConfig;
+use crate::error::Result;
+
+pub const DEFAULT_SUB_ID: Bits256 = Bits256([0; 32]);
+
+/*
+ * ---- Deployment
+ */
+
+pub fn random_salt() -> Salt {
+    // Generate a random 32-byte array
+    let mut rng = rand::thread_rng();
+    let mut bytes = [0u8; 32];
+    rng.fill(&mut bytes);
+
+    Salt::new(bytes)
+}
+
+/*
+  Salt derived as sha256("<seed>:<label>"). The same seed and label always
+  give the same contract id for the same bytecode and configurables.
+ */
+pub fn deterministic_salt(seed: &str, label: &str) -> Salt {
+    let mut hasher = Sha256::new();
+    hasher.update(seed.as_bytes());
+    hasher.update(b":");
+    hasher.update(label.as_bytes());
+    Salt::new(<[u8; 32]>::from(hasher.finalize()))
+}
+
+/*
+  Deterministic salt when a seed is given, random otherwise
+ */
+pub fn salt_for(seed: Option<&str>, label: &str) -> Salt {
+    match seed {
+        Some(seed) => deterministic_salt(seed, label),
+        None => random_salt(),
+    }
+}
+
+/*
+  Contract id the binary would get when deployed with the given salt and configurables
+ */
+pub fn predict_contract_id(
+    binary: &str,
+    salt: Salt,
+    configurables: impl Into<Configurables>,
+) -> Result<ContractId> {
+    let contract = Contract::load_from(
+        binary,
+        LoadConfiguration::default()
+        .with_salt(salt)
+        .with_configurables(configurables),
+    )?;
+
+    Ok(contract.contract_id())
+}
+
+pub async fn deploy_contract(
+    binary: &str,
+    wallet: &WalletUnlocked,
+    salt: Salt,
+    configurables: impl Into<Configurables>,
+    tx_config: TxConfig,
+) -> Result<ContractId> {
+    let id = Contract::load_from(
+        binary,
+        LoadConfiguration::default()
+        .with_salt(salt)
+        .with_configurables(configurables),
+    )?
+    .deploy(wallet, tx_config.tx_policies())
+    .await?;
+
+    Ok(id.into())
+}
+
+/*
+ * ---- Asset IDs
+ */
+
+pub fn get_asset_id(sub_id: Bytes32, contract: ContractId) -> AssetId {
+    let mut hasher = Sha256::new();
+    hasher.update(*contract);
+    hasher.update(*sub_id);
+    AssetId::new(*Bytes32::from(<[u8; 32]>::from(hasher.finalize())))
+}
+
+pub fn get_default_asset_id(contract: ContractId) -> AssetId {
+    let default_sub_id = Bytes32::from([0u8; 32]);
+    get_asset_id(default_sub_id, contract)
+}
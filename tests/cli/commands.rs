@@ -0,0 +1,157 @@
+use crate::utils::cli::*;
+
+use funi_sdk::{Factory, Pair, TxConfig};
+use funi_sdk::pair::get_amount_out;
+
+use fuels::prelude::*;
+
+const SUPPLY: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn test_cli_deploy_token() {
+    let node = CliNode::launch().await;
+
+    let output = node.run(&["deploy-token", "--name", "TokeA", "--symbol", "TKA", "--supply", "1000000"]).await;
+    let asset_id = asset_id(&output["asset_id"]);
+    assert!(output["mint_tx_id"].is_string());
+    assert_eq!(node.wallet.get_asset_balance(&asset_id).await.unwrap(), 1_000_000);
+
+    /* without a supply nothing is minted */
+    let output = node.run(&["deploy-token", "--symbol", "TKB"]).await;
+    assert!(output["mint_tx_id"].is_null());
+}
+
+#[tokio::test]
+async fn test_cli_deploy_pair() {
+    let node = CliNode::launch().await;
+    let (pair, token0, token1) = node.deploy_pair(SUPPLY).await;
+
+    let pair = Pair::attach(pair, node.wallet.clone(), TxConfig::default());
+    assert_eq!(pair.clone().call_token0().await.unwrap(), token0);
+    assert_eq!(pair.clone().call_token1().await.unwrap(), token1);
+}
+
+#[tokio::test]
+async fn test_cli_create_pair() {
+    let node = CliNode::launch().await;
+    let token_a = node.deploy_token("TKA", SUPPLY).await;
+    let token_b = node.deploy_token("TKB", SUPPLY).await;
+
+    let output = node.run(&[
+        "create-pair",
+        "--token-a", &token_a.to_string(),
+        "--token-b", &token_b.to_string(),
+    ]).await;
+    let factory = Factory::attach(contract_id(&output["factory_id"]), node.wallet.clone(), TxConfig::default());
+    let pair_id = contract_id(&output["pair_id"]);
+    assert_eq!(factory.clone().call_get_pair(token_b, token_a, false).await.unwrap(), Some(pair_id));
+
+    /* the same factory takes the pair of other tokens */
+    let token_c = node.deploy_token("TKC", SUPPLY).await;
+    let output = node.run(&[
+        "create-pair",
+        "--token-a", &token_a.to_string(),
+        "--token-b", &token_c.to_string(),
+        "--factory", &factory.contract_id.to_string(),
+    ]).await;
+    assert_eq!(contract_id(&output["factory_id"]), factory.contract_id);
+    assert_eq!(
+        factory.clone().call_get_pair(token_a, token_c, false).await.unwrap(),
+        Some(contract_id(&output["pair_id"]))
+    );
+}
+
+#[tokio::test]
+async fn test_cli_add_liquidity() {
+    let node = CliNode::launch().await;
+    let (pair, token0, token1) = node.deploy_pair(SUPPLY).await;
+
+    let output = node.run(&add_liquidity_args(pair, token0, token1, 10_000, 1_000_000, 0)).await;
+    assert_eq!(output["liquidity"], 100_000 - 1000);
+    assert!(output["tx_id"].is_string());
+
+    /* the minimum is checked by the script */
+    let error = node.try_run(&add_liquidity_args(pair, token0, token1, 10_000, 1_000_000, 200_000)).await.unwrap_err();
+    assert!(error["error"].is_string());
+
+    let output = node.run(&["reserves", "--pair", &pair.to_string()]).await;
+    assert_eq!(output["reserve0"], 10_000);
+    assert_eq!(output["reserve1"], 1_000_000);
+}
+
+#[tokio::test]
+async fn test_cli_swap() {
+    let node = CliNode::launch().await;
+    let (pair, token0, token1) = node.deploy_pair(SUPPLY).await;
+    node.run(&add_liquidity_args(pair, token0, token1, 100_000, 100_000, 0)).await;
+
+    let expected = get_amount_out(1000, 100_000, 100_000).unwrap();
+    let swap_args = |min_amount_out: u64| [
+        "swap".to_string(),
+        "--pair".to_string(), pair.to_string(),
+        "--asset-in".to_string(), token0.to_string(),
+        "--amount-in".to_string(), "1000".to_string(),
+        "--min-amount-out".to_string(), min_amount_out.to_string(),
+    ];
+
+    /* above the output the script reverts and nothing moves */
+    assert!(node.try_run(&swap_args(expected + 1)).await.is_err());
+
+    let output = node.run(&swap_args(expected)).await;
+    assert_eq!(output["amount_out"], expected);
+
+    let output = node.run(&["reserves", "--pair", &pair.to_string()]).await;
+    assert_eq!(output["reserve0"], 101_000);
+    assert_eq!(output["reserve1"], 100_000 - expected);
+}
+
+#[tokio::test]
+async fn test_cli_remove_liquidity() {
+    let node = CliNode::launch().await;
+    let (pair, token0, token1) = node.deploy_pair(SUPPLY).await;
+    node.run(&add_liquidity_args(pair, token0, token1, 100_000, 100_000, 0)).await;
+
+    let output = node.run(&["remove-liquidity", "--pair", &pair.to_string(), "--liquidity", "9000"]).await;
+    assert_eq!(output["amount0"], 9000);
+    assert_eq!(output["amount1"], 9000);
+    assert!(output["tx_id"].is_string());
+    assert_eq!(node.wallet.get_asset_balance(&token0).await.unwrap(), SUPPLY - 100_000 + 9000);
+}
+
+#[tokio::test]
+async fn test_cli_reserves_and_state() {
+    let node = CliNode::launch().await;
+    let (pair, token0, token1) = node.deploy_pair(SUPPLY).await;
+
+    let output = node.run(&["reserves", "--pair", &pair.to_string()]).await;
+    assert_eq!(output["reserve0"], 0);
+    assert_eq!(output["reserve1"], 0);
+
+    node.run(&add_liquidity_args(pair, token0, token1, 40_000, 90_000, 0)).await;
+    let output = node.run(&["state", "--pair", &pair.to_string()]).await;
+    assert_eq!(output["reserve0"], 40_000);
+    assert_eq!(output["reserve1"], 90_000);
+    /* u256 values are decimal strings */
+    assert_eq!(output["k"], (40_000u64 * 90_000).to_string());
+    assert_eq!(asset_id(&output["token0"]), token0);
+    assert_eq!(asset_id(&output["token1"]), token1);
+}
+
+fn add_liquidity_args(
+    pair: ContractId,
+    token0: AssetId,
+    token1: AssetId,
+    amount0: u64,
+    amount1: u64,
+    min_liquidity: u64,
+) -> Vec<String> {
+    vec![
+        "add-liquidity".to_string(),
+        "--pair".to_string(), pair.to_string(),
+        "--token0".to_string(), token0.to_string(),
+        "--token1".to_string(), token1.to_string(),
+        "--amount0".to_string(), amount0.to_string(),
+        "--amount1".to_string(), amount1.to_string(),
+        "--min-liquidity".to_string(), min_liquidity.to_string(),
+    ]
+}
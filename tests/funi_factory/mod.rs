@@ -0,0 +1 @@
+pub mod pairs;
\ No newline at end of file
@@ -0,0 +1,98 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::funi_factory::new_factory;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::stable_pair::stable_pair_configurables_for;
+
+use fuels::{
+    prelude::*,
+    types::ContractId,
+};
+
+#[tokio::test]
+async fn test_funi_factory_create_pair() {
+    let factory = new_factory().await;
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+
+    let funi_pair_configurables = create_funi_pair_configurables(token0_asset_id, token1_asset_id).unwrap()
+        .with_factory(factory.contract_id()).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+
+    /* registered under the sorted tokens, whatever the order given */
+    let registered = factory.clone().call_create_pair(token1_asset_id, token0_asset_id, false, pair.contract_id()).await.unwrap();
+    assert_eq!(registered, pair.contract_id());
+    assert_eq!(factory.clone().call_get_pair(token0_asset_id, token1_asset_id, false).await.unwrap(), Some(pair.contract_id()));
+    assert_eq!(factory.clone().call_get_pair(token1_asset_id, token0_asset_id, false).await.unwrap(), Some(pair.contract_id()));
+
+    let result = factory.clone().call_create_pair(token0_asset_id, token1_asset_id, false, pair.contract_id()).await;
+    assert!(result.unwrap_err().is_revert(FactoryError::PairExists));
+
+    let result = factory.clone().call_create_pair(token0_asset_id, token0_asset_id, false, pair.contract_id()).await;
+    assert!(result.unwrap_err().is_revert(FactoryError::IdenticalAssetIds));
+}
+
+#[tokio::test]
+async fn test_funi_factory_rejects_mismatched_pair() {
+    let factory = new_factory().await;
+    let (token0, token1) = new_sorted_tokens().await;
+    let (other0, other1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+
+    /* a pair of other tokens can't be registered for these ones */
+    let funi_pair_configurables = create_funi_pair_configurables(
+        other0.get_default_asset_id(),
+        other1.get_default_asset_id()
+    ).unwrap().with_factory(factory.contract_id()).unwrap();
+    let other_pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+    let result = factory.clone().call_create_pair(token0_asset_id, token1_asset_id, false, other_pair.contract_id()).await;
+    assert!(result.unwrap_err().is_revert(FactoryError::PairTokensMismatch));
+
+    /* neither can a pair deployed for another factory, or for none */
+    let funi_pair_configurables = create_funi_pair_configurables(token0_asset_id, token1_asset_id).unwrap();
+    for factory_id in [ContractId::zeroed(), ContractId::from([7u8; 32])] {
+        let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+            funi_pair_configurables.clone().with_factory(factory_id).unwrap()
+        ).await;
+        let result = factory.clone().call_create_pair(token0_asset_id, token1_asset_id, false, pair.contract_id()).await;
+        assert!(result.unwrap_err().is_revert(FactoryError::PairFactoryMismatch));
+    }
+
+    /* nothing got registered */
+    assert_eq!(factory.clone().call_get_pair(token0_asset_id, token1_asset_id, false).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_funi_factory_registers_pair_types_separately() {
+    let factory = new_factory().await;
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+
+    let volatile = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0_asset_id, token1_asset_id).unwrap()
+            .with_factory(factory.contract_id()).unwrap()
+    ).await;
+    let stable = ContractInstance::<FuniSwapV2StablePair<WalletUnlocked>>::new_with_configurables(
+        stable_pair_configurables_for(&token0, &token1).await.unwrap()
+            .with_factory(factory.contract_id()).unwrap()
+    ).await;
+
+    assert!(!volatile.clone().call_stable().await.unwrap());
+    assert!(stable.clone().call_stable().await.unwrap());
+
+    /* a pair is only registered as its own type */
+    let result = factory.clone().call_create_pair(token0_asset_id, token1_asset_id, true, volatile.contract_id()).await;
+    assert!(result.unwrap_err().is_revert(FactoryError::PairTypeMismatch));
+    let result = factory.clone().call_create_pair(token0_asset_id, token1_asset_id, false, stable.contract_id()).await;
+    assert!(result.unwrap_err().is_revert(FactoryError::PairTypeMismatch));
+
+    /* both types of the same tokens live side by side */
+    factory.clone().call_create_pair(token0_asset_id, token1_asset_id, false, volatile.contract_id()).await.unwrap();
+    factory.clone().call_create_pair(token1_asset_id, token0_asset_id, true, stable.contract_id()).await.unwrap();
+    assert_eq!(factory.clone().call_get_pair(token0_asset_id, token1_asset_id, false).await.unwrap(), Some(volatile.contract_id()));
+    assert_eq!(factory.clone().call_get_pair(token0_asset_id, token1_asset_id, true).await.unwrap(), Some(stable.contract_id()));
+}
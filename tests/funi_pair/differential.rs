@@ -0,0 +1,214 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::model::{
+    PairModel,
+    PairRevert,
+};
+use funi_sdk::pair::get_amount_out;
+
+use fuels::{
+    prelude::*,
+    types::{
+        Identity,
+        U256,
+    }
+};
+
+use proptest::prelude::*;
+use proptest::test_runner::{
+    Config,
+    TestCaseError,
+    TestRunner,
+};
+
+const CASES: u32 = 16;
+const MAX_OPS: usize = 10;
+
+/*
+    Unlike the invariant tests, operations are not filtered: whenever the
+    model reverts the contract has to revert as well.
+*/
+#[derive(Clone, Debug)]
+pub(crate) enum DiffOp {
+    Deposit { amount0: u64, amount1: u64 },
+    Mint,
+    /* burn share_bps / 10000 of the LP tokens held by the wallet */
+    Burn { share_bps: u64 },
+    /* deposit amount_in and ask for get_amount_out() + extra_out */
+    Swap { zero_for_one: bool, amount_in: u64, extra_out: u64 },
+}
+
+fn diff_op() -> impl Strategy<Value = DiffOp> {
+    prop_oneof![
+        (0u64..1_000_000, 0u64..1_000_000)
+            .prop_map(|(amount0, amount1)| DiffOp::Deposit { amount0, amount1 }),
+        Just(DiffOp::Mint),
+        (0u64..=10_000)
+            .prop_map(|share_bps| DiffOp::Burn { share_bps }),
+        (any::<bool>(), 1u64..1_000_000, 0u64..3)
+            .prop_map(|(zero_for_one, amount_in, extra_out)| DiffOp::Swap { zero_for_one, amount_in, extra_out }),
+    ]
+}
+
+#[test]
+fn test_pair_model_scripted() {
+    /* same amounts and results as test_funi_mint_initial and test_funi_burn_initial */
+    let mut model = PairModel::new();
+    model.deposit(1000, 10000);
+    assert_eq!(model.mint(), Ok(2162));
+    assert_eq!(model.total_supply, U256::from(3162));
+    assert_eq!(model.burn(2162), Ok((683, 6837)));
+
+    /* same amounts as test_funi_swap */
+    let mut model = PairModel::new();
+    model.deposit(10000, 100000);
+    model.mint().unwrap();
+    model.deposit(1000, 0);
+    assert_eq!(model.swap(0, 9067), Err(PairRevert::KInvariantIncorrect));
+    assert_eq!(model.swap(0, 9066), Ok(()));
+    assert_eq!(model.get_reserves(), Ok((11000, 90934)));
+
+    /* first mint has to lock MINIMUM_LIQUIDITY */
+    let mut model = PairModel::new();
+    model.deposit(1000, 1000);
+    assert_eq!(model.mint(), Err(PairRevert::InsufficientLiquidity));
+    model.deposit(10, 0);
+    assert_eq!(model.mint(), Ok(4));
+}
+
+#[test]
+fn test_pair_model_differential() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mut runner = TestRunner::new(Config {
+        cases: CASES,
+        max_shrink_iters: 64,
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    let result = runner.run(&prop::collection::vec(diff_op(), 1..MAX_OPS), |ops| {
+        runtime.block_on(run_differential(ops))
+    });
+
+    /* on failure the error holds the shrunk sequence of operations */
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
+
+pub(crate) async fn run_differential(ops: Vec<DiffOp>) -> std::result::Result<(), TestCaseError> {
+    let (token0, token1) = new_sorted_tokens().await;
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id()
+    ).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+    let to = pair.deployer_identity();
+    let mut model = PairModel::new();
+
+    for op in ops {
+        match op {
+            DiffOp::Deposit { amount0, amount1 } => {
+                deposit(&pair, &token0, to, amount0).await;
+                deposit(&pair, &token1, to, amount1).await;
+                model.deposit(amount0, amount1);
+            }
+            DiffOp::Mint => {
+                let expected = model.mint();
+                let result = pair.clone().call_mint(to).await;
+                compare("mint", expected, result)?;
+            }
+            DiffOp::Burn { share_bps } => {
+                let lp_balance = pair.deployer_balance(pair.get_default_asset_id()).await.unwrap();
+                let liquidity = (lp_balance as u128 * share_bps as u128 / 10_000) as u64;
+                if liquidity == 0 {
+                    /* the SDK can't forward zero coins */
+                    continue;
+                }
+                let expected = model.burn(liquidity);
+                let result = pair.clone().call_burn(to, liquidity).await;
+                compare("burn", expected, result)?;
+            }
+            DiffOp::Swap { zero_for_one, amount_in, extra_out } => {
+                let (reserve0, reserve1) = model.get_reserves().unwrap();
+                let (amount0_out, amount1_out) = if zero_for_one {
+                    deposit(&pair, &token0, to, amount_in).await;
+                    model.deposit(amount_in, 0);
+                    (0, get_amount_out(amount_in, reserve0, reserve1).unwrap_or(0).saturating_add(extra_out))
+                } else {
+                    deposit(&pair, &token1, to, amount_in).await;
+                    model.deposit(0, amount_in);
+                    (get_amount_out(amount_in, reserve1, reserve0).unwrap_or(0).saturating_add(extra_out), 0)
+                };
+                let expected = model.swap(amount0_out, amount1_out);
+                let result = pair.clone().call_swap(amount0_out, amount1_out, to).await;
+                compare("swap", expected, result)?;
+            }
+        }
+
+        /* stored state matches after every step */
+        let reserves = pair.clone().call_get_reserves().await.unwrap();
+        prop_assert_eq!(model.get_reserves().unwrap(), reserves);
+        let total_supply = pair.clone().call_total_supply().await.unwrap();
+        prop_assert_eq!(model.total_supply, U256::from(total_supply));
+        let balance0 = pair.get_contract_balance(token0.get_default_asset_id()).await.unwrap();
+        let balance1 = pair.get_contract_balance(token1.get_default_asset_id()).await.unwrap();
+        prop_assert_eq!(model.balance0, U256::from(balance0));
+        prop_assert_eq!(model.balance1, U256::from(balance1));
+    }
+    Ok(())
+}
+
+/*
+    Both succeed with the same output, or both revert for the same reason.
+*/
+fn compare<T: PartialEq + std::fmt::Debug>(
+    method: &str,
+    expected: std::result::Result<T, PairRevert>,
+    result: funi_sdk::Result<T>,
+) -> std::result::Result<(), TestCaseError> {
+    match (expected, result) {
+        (Ok(expected), Ok(value)) => {
+            prop_assert_eq!(expected, value, "{} output differs", method);
+        }
+        (Err(revert), Err(e)) => {
+            prop_assert!(
+                revert.matches(&e),
+                "{} reverted with `{}`, model expected `{}`", method, e, revert
+            );
+        }
+        (Ok(expected), Err(e)) => {
+            return Err(TestCaseError::fail(format!("{} reverted with `{}`, model returned {:?}", method, e, expected)));
+        }
+        (Err(revert), Ok(value)) => {
+            return Err(TestCaseError::fail(format!("{} returned {:?}, model reverted with `{}`", method, value, revert)));
+        }
+    }
+    Ok(())
+}
+
+/*
+    Mint tokens to the wallet and transfer them to the pair.
+*/
+async fn deposit(
+    pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    token: &ContractInstance::<SRC20<WalletUnlocked>>,
+    to: Identity,
+    amount: u64,
+) {
+    if amount == 0 {
+        return;
+    }
+    token.clone().call_mint(to, DEFAULT_SUB_ID, amount).await.unwrap();
+    pair.deployer_wallet().transfer_to_contract(
+        pair.contract_id(),
+        token.get_default_asset_id(),
+        amount,
+        pair.tx_config
+    ).await.unwrap();
+}
@@ -0,0 +1,182 @@
+/*
+    Pairs with a token that doesn't deliver the amount sent (fee-on-transfer) or
+    whose balances grow on their own (rebasing). The pair only looks at its
+    balances, so:
+    - mint() is safe: the LP tokens match what the pair received.
+    - swap() with the output priced from the amount sent is not: the pair got
+      less and the K check reverts. Price from what the pair received with
+      swap_exact_tokens_for_tokens_supporting_fee_on_transfer() instead, which
+      sends the input through the token's taxed transfer() itself.
+    - a rebase of the pair balance is not credited to the LPs: it is taken by
+      the next swap as free input.
+*/
+
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+
+use funi_sdk::pair::get_amount_out;
+
+use fuels::{
+    prelude::*,
+    types::{
+        AssetId,
+        Identity,
+    }
+};
+
+#[tokio::test]
+async fn test_funi_fee_on_transfer_mint() {
+    let (taxed, token, pair, _router02) = setup_taxed_pair().await;
+    let to = pair.deployer_identity();
+
+    /* 1% of the taxed token is burnt on the way to the pair */
+    taxed.clone().call_mint(to, DEFAULT_SUB_ID, 10000).await.unwrap();
+    let received = taxed.clone().call_transfer(Identity::ContractId(pair.contract_id()), 10000).await.unwrap();
+    assert_eq!(received, 9900);
+    token.clone().call_mint(to, DEFAULT_SUB_ID, 10000).await.unwrap();
+    pair.deployer_wallet().transfer_to_contract(pair.contract_id(), token.get_default_asset_id(), 10000, pair.tx_config).await.unwrap();
+
+    /* sqrt(9900 * 10000) - MINIMUM_LIQUIDITY, the LP tokens match what was received */
+    let liquidity = pair.clone().call_mint(to).await.unwrap();
+    assert_eq!(liquidity, 8949);
+    assert_eq!(get_reserves_for(&pair, taxed.get_default_asset_id()).await, (9900, 10000));
+}
+
+#[tokio::test]
+async fn test_funi_fee_on_transfer_swap() {
+    let (taxed, token, pair, router02) = setup_taxed_pair().await;
+    let taxed_asset_id = taxed.get_default_asset_id();
+    let to = pair.deployer_identity();
+    add_liquidity(&taxed, &token, &pair, 100000, 100000).await;
+    let (reserve_in, reserve_out) = get_reserves_for(&pair, taxed_asset_id).await;
+
+    taxed.clone().call_mint(to, DEFAULT_SUB_ID, 1000).await.unwrap();
+    let received = taxed.clone().call_transfer(Identity::ContractId(pair.contract_id()), 1000).await.unwrap();
+    assert_eq!(received, 990);
+
+    /* priced from the amount sent, the pair is short of input */
+    let amount_out = get_amount_out(1000, reserve_in, reserve_out).unwrap();
+    let (amount0_out, amount1_out) = if pair.clone().call_token0().await.unwrap() == taxed_asset_id {
+        (0, amount_out)
+    } else {
+        (amount_out, 0)
+    };
+    let result = pair.clone().call_swap(amount0_out, amount1_out, to).await;
+    assert!(result.unwrap_err().is_revert(PairError::KInvariantIncorrect));
+
+    /*
+        the router sends the input through the taxed transfer(), so the fee is taken and
+        only the 990 the pair received from it are priced, not the 990 already in the pair
+    */
+    taxed.clone().call_mint(to, DEFAULT_SUB_ID, 2000).await.unwrap();
+    let expected = get_amount_out(990, reserve_in, reserve_out).unwrap();
+    let amount_out = router02.clone().call_swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        pair.contract_id(), taxed.contract_id(), 1000, 0, to
+    ).await.unwrap();
+    assert_eq!(amount_out, expected);
+    /* the surplus went to the pair as extra input */
+    let (reserve_in, reserve_out) = get_reserves_for(&pair, taxed_asset_id).await;
+    assert_eq!(reserve_in, 99000 + received + 990);
+
+    let expected = get_amount_out(990, reserve_in, reserve_out).unwrap();
+    let result = router02.clone().call_swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        pair.contract_id(), taxed.contract_id(), 1000, expected + 1, to
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientOutputAmount));
+    let amount_out = router02.clone().call_swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        pair.contract_id(), taxed.contract_id(), 1000, expected, to
+    ).await.unwrap();
+    assert_eq!(amount_out, expected);
+    assert_eq!(get_reserves_for(&pair, taxed_asset_id).await, (reserve_in + 990, reserve_out - expected));
+}
+
+#[tokio::test]
+async fn test_funi_rebase_taken_by_next_swap() {
+    let (taxed, token, pair, _router02) = setup_taxed_pair().await;
+    let taxed_asset_id = taxed.get_default_asset_id();
+    let to = pair.deployer_identity();
+    add_liquidity(&taxed, &token, &pair, 100000, 100000).await;
+    let (reserve_in, reserve_out) = get_reserves_for(&pair, taxed_asset_id).await;
+
+    /* +1% of the pair balance, not in the reserves */
+    let rebased = taxed.clone().call_rebase(pair.contract_id()).await.unwrap();
+    assert_eq!(rebased, reserve_in / 100);
+
+    /* LPs can't claim it with a one-sided mint */
+    let result = pair.clone().call_mint(to).await;
+    assert!(result.unwrap_err().is_revert(PairError::InsufficientLiquidity));
+
+    /* the next swap uses it as input, without sending anything */
+    let balance_before = pair.deployer_balance(token.get_default_asset_id()).await.unwrap();
+    let amount_out = get_amount_out(rebased, reserve_in, reserve_out).unwrap();
+    let (amount0_out, amount1_out) = if pair.clone().call_token0().await.unwrap() == taxed_asset_id {
+        (0, amount_out)
+    } else {
+        (amount_out, 0)
+    };
+    pair.clone().call_swap(amount0_out, amount1_out, to).await.unwrap();
+    assert_eq!(pair.deployer_balance(token.get_default_asset_id()).await.unwrap(), balance_before + amount_out);
+}
+
+/*
+    ---- Helper functions
+*/
+
+/*
+    Taxed token (1% fee, 1% rebase), a regular token, their pair and a router for it.
+*/
+async fn setup_taxed_pair() -> (
+    ContractInstance::<SRC20Taxed<WalletUnlocked>>,
+    ContractInstance::<SRC20<WalletUnlocked>>,
+    ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>,
+) {
+    let taxed = ContractInstance::<SRC20Taxed<WalletUnlocked>>::new_with_configurables(
+        create_src20_taxed_configurables(100, 100).unwrap()
+    ).await;
+    let token = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(taxed.get_default_asset_id(), token.get_default_asset_id()).unwrap()
+    ).await;
+    let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(
+        create_funi_router02_pair_configurables(
+            taxed.get_default_asset_id(),
+            token.get_default_asset_id(),
+            pair.contract_id()
+        ).unwrap()
+    ).await;
+    (taxed, token, pair, router02)
+}
+
+/*
+    Deposit through the taxed transfer and mint, the pair receives 99% of amount_taxed.
+*/
+async fn add_liquidity(
+    taxed: &ContractInstance::<SRC20Taxed<WalletUnlocked>>,
+    token: &ContractInstance::<SRC20<WalletUnlocked>>,
+    pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    amount_taxed: u64,
+    amount: u64,
+) {
+    let to = pair.deployer_identity();
+    taxed.clone().call_mint(to, DEFAULT_SUB_ID, amount_taxed).await.unwrap();
+    taxed.clone().call_transfer(Identity::ContractId(pair.contract_id()), amount_taxed).await.unwrap();
+    token.clone().call_mint(to, DEFAULT_SUB_ID, amount).await.unwrap();
+    pair.deployer_wallet().transfer_to_contract(pair.contract_id(), token.get_default_asset_id(), amount, pair.tx_config).await.unwrap();
+    pair.clone().call_mint(to).await.unwrap();
+}
+
+/*
+    Reserves of the pair as (reserve of asset, reserve of the other token)
+*/
+async fn get_reserves_for(
+    pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    asset: AssetId,
+) -> (u64, u64) {
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    if pair.clone().call_token0().await.unwrap() == asset {
+        (reserve0, reserve1)
+    } else {
+        (reserve1, reserve0)
+    }
+}
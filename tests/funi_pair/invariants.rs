@@ -0,0 +1,255 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::pair::get_amount_out;
+
+use fuels::{
+    prelude::*,
+    types::Identity,
+};
+
+use proptest::prelude::*;
+use proptest::test_runner::{
+    Config,
+    TestCaseError,
+    TestRunner,
+};
+
+const MINIMUM_LIQUIDITY: u64 = 1000;
+
+/*
+    Each case deploys fresh contracts, so keep the number of cases low.
+*/
+const CASES: u32 = 16;
+const MAX_OPS: usize = 8;
+
+#[derive(Clone, Debug)]
+enum PairOp {
+    Mint { amount0: u64, amount1: u64 },
+    /* burn share_bps / 10000 of the LP tokens held by the wallet */
+    Burn { share_bps: u64 },
+    Swap { zero_for_one: bool, amount_in: u64 },
+}
+
+fn pair_op() -> impl Strategy<Value = PairOp> {
+    prop_oneof![
+        (1_000u64..1_000_000, 1_000u64..1_000_000)
+            .prop_map(|(amount0, amount1)| PairOp::Mint { amount0, amount1 }),
+        (1u64..=10_000)
+            .prop_map(|share_bps| PairOp::Burn { share_bps }),
+        (any::<bool>(), 1u64..1_000_000)
+            .prop_map(|(zero_for_one, amount_in)| PairOp::Swap { zero_for_one, amount_in }),
+    ]
+}
+
+/*
+    Initial liquidity followed by a random sequence of operations.
+*/
+fn pair_session() -> impl Strategy<Value = ((u64, u64), Vec<PairOp>)> {
+    (
+        (2_000u64..1_000_000, 2_000u64..1_000_000),
+        prop::collection::vec(pair_op(), 1..MAX_OPS),
+    )
+}
+
+#[test]
+fn test_funi_pair_invariants() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mut runner = TestRunner::new(Config {
+        cases: CASES,
+        max_shrink_iters: 64,
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    let result = runner.run(&pair_session(), |(initial, ops)| {
+        runtime.block_on(run_session(initial, ops))
+    });
+
+    /* on failure the error holds the shrunk sequence of operations */
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
+
+async fn run_session(initial: (u64, u64), ops: Vec<PairOp>) -> std::result::Result<(), TestCaseError> {
+    let mut session = PairSession::new().await;
+
+    session.mint(initial.0, initial.1).await?;
+    session.check_invariants().await?;
+
+    for op in ops {
+        match op {
+            PairOp::Mint { amount0, amount1 } => session.mint(amount0, amount1).await?,
+            PairOp::Burn { share_bps } => session.burn(share_bps).await?,
+            PairOp::Swap { zero_for_one, amount_in } => session.swap(zero_for_one, amount_in).await?,
+        }
+        session.check_invariants().await?;
+    }
+    Ok(())
+}
+
+/*
+    ---- Session state
+*/
+
+struct PairSession {
+    token0: ContractInstance::<SRC20<WalletUnlocked>>,
+    token1: ContractInstance::<SRC20<WalletUnlocked>>,
+    pair: ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    to: Identity,
+    /* LP tokens minted to and burned by the wallet */
+    minted: u64,
+    burned: u64,
+}
+
+impl PairSession {
+    async fn new() -> Self {
+        let (token0, token1) = new_sorted_tokens().await;
+        let funi_pair_configurables = create_funi_pair_configurables(
+            token0.get_default_asset_id(),
+            token1.get_default_asset_id()
+        ).unwrap();
+        let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+        let to = pair.deployer_identity();
+
+        Self {
+            token0,
+            token1,
+            pair,
+            to,
+            minted: 0,
+            burned: 0,
+        }
+    }
+
+    async fn reserves(&self) -> (u64, u64) {
+        self.pair.clone().call_get_reserves().await.unwrap()
+    }
+
+    async fn total_supply(&self) -> u64 {
+        self.pair.clone().call_total_supply().await.unwrap()
+    }
+
+    /*
+        Mint tokens to the wallet and transfer them to the pair.
+    */
+    async fn deposit(&self, token: &ContractInstance::<SRC20<WalletUnlocked>>, amount: u64) {
+        token.clone().call_mint(self.to, DEFAULT_SUB_ID, amount).await.unwrap();
+        self.pair.deployer_wallet().transfer_to_contract(
+            self.pair.contract_id(),
+            token.get_default_asset_id(),
+            amount,
+            self.pair.tx_config
+        ).await.unwrap();
+    }
+
+    async fn mint(&mut self, amount0: u64, amount1: u64) -> std::result::Result<(), TestCaseError> {
+        /* a reverted mint would leave the deposit in the pair, so skip mints that mint nothing */
+        let (reserve0, reserve1) = self.reserves().await;
+        let total_supply = self.total_supply().await as u128;
+        let expected = if total_supply == 0 {
+            isqrt(amount0 as u128 * amount1 as u128).saturating_sub(MINIMUM_LIQUIDITY as u128)
+        } else {
+            std::cmp::min(
+                amount0 as u128 * total_supply / reserve0 as u128,
+                amount1 as u128 * total_supply / reserve1 as u128,
+            )
+        };
+        if expected == 0 {
+            return Ok(());
+        }
+
+        self.deposit(&self.token0, amount0).await;
+        self.deposit(&self.token1, amount1).await;
+        let liquidity = self.pair.clone().call_mint(self.to).await
+            .map_err(|e| TestCaseError::fail(format!("mint({}, {}) failed: {}", amount0, amount1, e)))?;
+        prop_assert!(liquidity > 0);
+        self.minted += liquidity;
+        Ok(())
+    }
+
+    async fn burn(&mut self, share_bps: u64) -> std::result::Result<(), TestCaseError> {
+        let lp_balance = self.pair.deployer_balance(self.pair.get_default_asset_id()).await.unwrap();
+        let liquidity = (lp_balance as u128 * share_bps as u128 / 10_000) as u64;
+        let (reserve0, reserve1) = self.reserves().await;
+        let total_supply = self.total_supply().await as u128;
+        if liquidity == 0
+            || liquidity as u128 * reserve0 as u128 / total_supply == 0
+            || liquidity as u128 * reserve1 as u128 / total_supply == 0 {
+            return Ok(());
+        }
+
+        self.pair.clone().call_burn(self.to, liquidity).await
+            .map_err(|e| TestCaseError::fail(format!("burn({}) failed: {}", liquidity, e)))?;
+        self.burned += liquidity;
+        Ok(())
+    }
+
+    async fn swap(&mut self, zero_for_one: bool, amount_in: u64) -> std::result::Result<(), TestCaseError> {
+        let (reserve0, reserve1) = self.reserves().await;
+        let (amount0_out, amount1_out) = if zero_for_one {
+            (0, get_amount_out(amount_in, reserve0, reserve1).unwrap_or(0))
+        } else {
+            (get_amount_out(amount_in, reserve1, reserve0).unwrap_or(0), 0)
+        };
+        if amount0_out == 0 && amount1_out == 0 {
+            return Ok(());
+        }
+
+        if zero_for_one {
+            self.deposit(&self.token0, amount_in).await;
+        } else {
+            self.deposit(&self.token1, amount_in).await;
+        }
+        self.pair.clone().call_swap(amount0_out, amount1_out, self.to).await
+            .map_err(|e| TestCaseError::fail(format!("swap({}, {}) failed: {}", amount0_out, amount1_out, e)))?;
+
+        /* K never decreases across swaps */
+        let (new_reserve0, new_reserve1) = self.reserves().await;
+        prop_assert!(
+            new_reserve0 as u128 * new_reserve1 as u128 >= reserve0 as u128 * reserve1 as u128,
+            "k decreased: {} * {} < {} * {}",
+            new_reserve0, new_reserve1, reserve0, reserve1
+        );
+        Ok(())
+    }
+
+    async fn check_invariants(&self) -> std::result::Result<(), TestCaseError> {
+        /* reserves equal the contract balances */
+        let (reserve0, reserve1) = self.reserves().await;
+        let balance0 = self.pair.get_contract_balance(self.token0.get_default_asset_id()).await.unwrap();
+        let balance1 = self.pair.get_contract_balance(self.token1.get_default_asset_id()).await.unwrap();
+        prop_assert_eq!(reserve0, balance0);
+        prop_assert_eq!(reserve1, balance1);
+
+        /* LP supply matches minted minus burned plus the locked minimum liquidity */
+        let total_supply = self.total_supply().await;
+        prop_assert_eq!(total_supply, self.minted - self.burned + MINIMUM_LIQUIDITY);
+
+        /* and the LP tokens held: the wallet's plus the ones locked in the pair */
+        let lp_asset_id = self.pair.get_default_asset_id();
+        let wallet_balance = self.pair.deployer_balance(lp_asset_id).await.unwrap();
+        let locked = self.pair.get_contract_balance(lp_asset_id).await.unwrap();
+        prop_assert_eq!(locked, MINIMUM_LIQUIDITY);
+        prop_assert_eq!(total_supply, wallet_balance + locked);
+        Ok(())
+    }
+}
+
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
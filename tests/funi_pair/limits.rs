@@ -0,0 +1,168 @@
+use crate::funi_pair::differential::{
+    run_differential,
+    DiffOp,
+};
+
+use funi_sdk::model::{
+    mul_div,
+    mul_gte,
+    mul_wide,
+    PairRevert,
+};
+
+use funi_sdk::pair::get_amount_out;
+
+use fuels::types::U256;
+
+use proptest::prelude::*;
+use proptest::test_runner::{
+    Config,
+    TestCaseError,
+    TestRunner,
+};
+
+/*
+    mul_div is pure, it can run many more cases than the contract tests.
+*/
+const MATH_CASES: u32 = 1024;
+const PAIR_CASES: u32 = 8;
+const MAX_OPS: usize = 10;
+
+/*
+    Values spread over the whole u256 range, right below U256::MAX and small ones.
+*/
+fn any_u256() -> impl Strategy<Value = U256> {
+    prop_oneof![
+        any::<[u8; 32]>().prop_map(|bytes| U256::from_big_endian(&bytes)),
+        (0u64..1_000).prop_map(|d| U256::MAX - U256::from(d)),
+        any::<u64>().prop_map(U256::from),
+    ]
+}
+
+#[test]
+fn test_mul_div_scripted() {
+    let max = U256::MAX;
+    assert_eq!(mul_div(max, max, max), Ok(max));
+    assert_eq!(mul_div(max, U256::from(2), U256::from(4)), Ok(max / 2));
+    assert_eq!(mul_div(max, max, U256::one()), Err(PairRevert::Overflow));
+    assert_eq!(mul_div(max, U256::one(), U256::zero()), Err(PairRevert::DivisionByZero));
+
+    /* (2^256 - 1)^2 = 2^512 - 2^257 + 1 */
+    assert_eq!(mul_wide(max, max), (max - 1, U256::one()));
+    assert!(mul_gte(max, max, max, max - 1));
+    assert!(!mul_gte(max, max - 1, max, max));
+}
+
+#[test]
+fn test_get_amount_out_limits() {
+    /* nothing swapped into an empty reserve */
+    assert_eq!(get_amount_out(0, 0, 1000), None);
+    assert_eq!(get_amount_out(1000, 0, 0), Some(0));
+    assert_eq!(get_amount_out(0, 1000, 1000), Some(0));
+
+    /* the output stays below reserve_out, the products don't overflow */
+    let max = u64::MAX;
+    let expected = (U256::from(max) * 997 / 1997).as_u64();
+    assert_eq!(get_amount_out(max, max, max), Some(expected));
+}
+
+#[test]
+fn test_mul_div_limits() {
+    let mut runner = TestRunner::new(Config {
+        cases: MATH_CASES,
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    let result = runner.run(&(any_u256(), any_u256(), any_u256()), |(a, b, c)| {
+        check_mul_div(a, b, c)
+    });
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
+
+fn check_mul_div(a: U256, b: U256, c: U256) -> std::result::Result<(), TestCaseError> {
+    /* low half of the product is the wrapping product */
+    let (hi, lo) = mul_wide(a, b);
+    prop_assert_eq!(lo, a.overflowing_mul(b).0);
+
+    if c.is_zero() {
+        prop_assert_eq!(mul_div(a, b, c), Err(PairRevert::DivisionByZero));
+        return Ok(());
+    }
+    if !a.is_zero() {
+        prop_assert_eq!(mul_div(a, b, a), Ok(b));
+    }
+    prop_assert_eq!(mul_div(a, b, c), mul_div(b, a, c));
+
+    match mul_div(a, b, c) {
+        Ok(quotient) => {
+            if let Some(product) = a.checked_mul(b) {
+                prop_assert_eq!(quotient, product / c);
+            }
+            /* quotient * c <= a * b < (quotient + 1) * c */
+            prop_assert!(mul_gte(a, b, quotient, c));
+            if quotient < U256::MAX {
+                prop_assert!(!mul_gte(a, b, quotient + 1, c));
+            }
+        }
+        Err(revert) => {
+            /* only when the result doesn't fit, i.e. a * b >= 2^256 * c */
+            prop_assert_eq!(revert, PairRevert::Overflow);
+            prop_assert!(hi >= c);
+        }
+    }
+    Ok(())
+}
+
+/*
+    Reserves and LP supply close to u64::MAX, the most a pair can hold. The
+    token supply is a u64 as well, so the amounts leave room for every op.
+*/
+fn limits_op() -> impl Strategy<Value = DiffOp> {
+    prop_oneof![
+        (0u64..1 << 59, 0u64..1 << 59)
+            .prop_map(|(amount0, amount1)| DiffOp::Deposit { amount0, amount1 }),
+        Just(DiffOp::Mint),
+        (0u64..=10_000)
+            .prop_map(|share_bps| DiffOp::Burn { share_bps }),
+        (any::<bool>(), 1u64..1 << 59, 0u64..3)
+            .prop_map(|(zero_for_one, amount_in, extra_out)| DiffOp::Swap { zero_for_one, amount_in, extra_out }),
+    ]
+}
+
+fn limits_session() -> impl Strategy<Value = Vec<DiffOp>> {
+    (
+        (1u64 << 62..1 << 63, 1u64 << 62..1 << 63),
+        prop::collection::vec(limits_op(), 1..MAX_OPS),
+    ).prop_map(|((amount0, amount1), ops)| {
+        let mut session = vec![DiffOp::Deposit { amount0, amount1 }, DiffOp::Mint];
+        session.extend(ops);
+        session
+    })
+}
+
+/*
+    Same checks as test_pair_model_differential, with the pair close to its limits.
+*/
+#[test]
+fn test_pair_model_limits() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mut runner = TestRunner::new(Config {
+        cases: PAIR_CASES,
+        max_shrink_iters: 64,
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    let result = runner.run(&limits_session(), |ops| {
+        runtime.block_on(run_differential(ops))
+    });
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
@@ -9,19 +9,19 @@ use fuels::{
         AssetId,
         bech32::Bech32ContractId,
         Identity,
+        U256,
     }
 };
 
 #[tokio::test]
 async fn test_funi_mint_initial() {
-    let token0 = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
-    let token1 = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+    let (token0, token1) = new_sorted_tokens().await;
     let token0_asset_id = token0.clone().get_default_asset_id();
     let token1_asset_id = token1.clone().get_default_asset_id();
     let funi_pair_configurables = create_funi_pair_configurables(
         token0_asset_id,
         token1_asset_id
-    );
+    ).unwrap();
     
     let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
     let depositor = get_deployer_identity().await;
@@ -29,34 +29,41 @@ async fn test_funi_mint_initial() {
     let amount1 = 10000;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await.unwrap();
 
     /* transfer assets to pair contract */
     let wallet = pair.deployer_wallet();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token0_asset_id,
-        amount0
-    ).await;
+        amount0,
+        pair.tx_config
+    ).await.unwrap();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token1_asset_id,
-        amount1
-    ).await;
+        amount1,
+        pair.tx_config
+    ).await.unwrap();
 
     let expected_liquidity = 2162;
     /* deploy initial liquidity */
-    let liquidity = pair.clone().call_mint(depositor).await;
+    let liquidity = pair.clone().call_mint(depositor).await.unwrap();
     assert_eq!(expected_liquidity, liquidity);
 
+    /* mint syncs the reserves to the deposited amounts */
+    let sync = pair.last_sync_event().await.unwrap().unwrap();
+    assert_eq!(sync.reserve0, U256::from(amount0));
+    assert_eq!(sync.reserve1, U256::from(amount1));
+
     /* get LP token balance */
     let pair_balance = pair.clone()
         .deployer_balance(
             get_default_asset_id(
                 pair.contract_id()
             )
-        ).await;
+        ).await.unwrap();
 
     assert_eq!(
         pair_balance, 
@@ -64,6 +71,34 @@ async fn test_funi_mint_initial() {
     )
 }
 
+#[tokio::test]
+async fn test_funi_minimum_liquidity_locked() {
+    let amount0 = 1000;
+    let amount1 = 10000;
+    let expected_locked = 1000;
+
+    let (_token0, _token1, pair, liquidity) = setup_funi_pair(
+        amount0,
+        amount1,
+    ).await;
+
+    /* the minimum liquidity is held by the pair itself */
+    let lp_asset_id = pair.get_default_asset_id();
+    let locked = pair.get_contract_balance(lp_asset_id).await.unwrap();
+    assert_eq!(expected_locked, locked);
+
+    /* total supply equals the sum of the holder balances */
+    let total_supply = pair.clone().call_total_supply().await.unwrap();
+    let depositor_balance = pair.deployer_balance(lp_asset_id).await.unwrap();
+    assert_eq!(depositor_balance, liquidity);
+    assert_eq!(total_supply, depositor_balance + locked);
+
+    /* burning everything the depositor holds leaves the locked tokens */
+    pair.clone().call_burn(pair.deployer_identity(), liquidity).await.unwrap();
+    assert_eq!(pair.clone().call_total_supply().await.unwrap(), expected_locked);
+    assert_eq!(pair.get_contract_balance(lp_asset_id).await.unwrap(), expected_locked);
+}
+
 #[tokio::test]
 async fn test_funi_burn_initial() {
     let amount0 = 1000;
@@ -80,18 +115,18 @@ async fn test_funi_burn_initial() {
     /* record balance before burning */
     let token0_asset_id = token0.clone().get_default_asset_id();
     let token1_asset_id = token1.clone().get_default_asset_id();
-    let token0_balance_before = pair.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_before = pair.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_before = pair.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_before = pair.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     /* call burn */
     let (received_token0, received_token1) = pair.clone().call_burn(
         pair.deployer_identity(),
         liquidity,
-    ).await;
+    ).await.unwrap();
 
     /* record balance after burning */
-    let token0_balance_after = pair.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_after = pair.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_after = pair.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_after = pair.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     /* verify account balance */
     assert_eq!(expected_amount0_out, token0_balance_after - token0_balance_before);
@@ -100,6 +135,54 @@ async fn test_funi_burn_initial() {
     /* verify returned values */
     assert_eq!(expected_amount0_out, received_token0);
     assert_eq!(expected_amount1_out, received_token1);
+
+    /* burn syncs the reserves to what is left */
+    let sync = pair.last_sync_event().await.unwrap().unwrap();
+    assert_eq!(sync.reserve0, U256::from(amount0 - expected_amount0_out));
+    assert_eq!(sync.reserve1, U256::from(amount1 - expected_amount1_out));
+}
+
+#[tokio::test]
+async fn test_funi_burn_partial() {
+    /* create pair instance with liquidity deployed, 2162 LP tokens out of 3162 */
+    let (token0, token1, pair, liquidity) = setup_funi_pair(1000, 10000).await;
+    let lp_asset_id = pair.get_default_asset_id();
+    let token0_asset_id = token0.clone().get_default_asset_id();
+    let token1_asset_id = token1.clone().get_default_asset_id();
+    let to = pair.deployer_identity();
+
+    /* burning more than was sent */
+    let result = pair.clone().call_burn_with(to, lp_asset_id, liquidity / 2, Some(liquidity)).await;
+    assert!(result.unwrap_err().is_revert(PairError::InsufficientLiquiditySent));
+
+    /* send all the LP tokens, burn half of them */
+    let (received_token0, received_token1) = pair.clone().call_burn_with(
+        to,
+        lp_asset_id,
+        liquidity,
+        Some(liquidity / 2),
+    ).await.unwrap();
+    assert_eq!((received_token0, received_token1), (341, 3418));
+
+    /* the other half came back */
+    assert_eq!(pair.deployer_balance(lp_asset_id).await.unwrap(), liquidity - liquidity / 2);
+    assert_eq!(pair.clone().call_total_supply().await.unwrap(), 3162 - liquidity / 2);
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), received_token0);
+    assert_eq!(pair.deployer_balance(token1_asset_id).await.unwrap(), received_token1);
+}
+
+#[tokio::test]
+async fn test_funi_burn_rejects_other_asset() {
+    let (token0, _token1, pair, _liquidity) = setup_funi_pair(1000, 10000).await;
+    let token0_asset_id = token0.clone().get_default_asset_id();
+    let to = pair.deployer_identity();
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, 100).await.unwrap();
+
+    /* checked before the amounts are computed, the coins stay with the sender */
+    let result = pair.clone().call_burn_with(to, token0_asset_id, 100, None).await;
+    assert!(result.unwrap_err().is_revert(PairError::IncorrectAsset));
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), 100);
+    assert_eq!(pair.clone().call_get_reserves().await.unwrap(), (1000, 10000));
 }
 
 #[tokio::test]
@@ -130,18 +213,18 @@ async fn test_funi_mint_second() {
     /* record balance before burning */
     let token0_asset_id = token0.clone().get_default_asset_id();
     let token1_asset_id = token1.clone().get_default_asset_id();
-    let token0_balance_before = pair.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_before = pair.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_before = pair.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_before = pair.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     /* call burn */
     let (received_token0, received_token1) = pair.clone().call_burn(
         pair.deployer_identity(),
         second_liquidity,
-    ).await;
+    ).await.unwrap();
 
     /* record balance after burning */
-    let token0_balance_after = pair.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_after = pair.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_after = pair.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_after = pair.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     /* verify account balance */
     assert_eq!(second_amount0, token0_balance_after - token0_balance_before);
@@ -163,11 +246,33 @@ async fn test_funi_get_reserves() {
         amount1,
     ).await;
 
-    let (reserve0, reserve1) = pair.call_get_reserves().await;
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
 
     assert_eq!(amount0, reserve0);
     assert_eq!(amount1, reserve1);
 
+    /* reading the reserves doesn't log a sync */
+    assert!(pair.last_sync_event().await.unwrap().is_none());
+
+}
+
+#[tokio::test]
+async fn test_funi_get_pair_state() {
+    let (token0, token1, pair, liquidity) = setup_funi_pair(1000, 10000).await;
+
+    let state = pair.clone().call_get_pair_state().await.unwrap();
+    assert_eq!(state.pair_id, pair.contract_id().to_string());
+    assert_eq!(state.lp_asset_id, pair.get_default_asset_id().to_string());
+    assert_eq!(state.token0, token0.get_default_asset_id().to_string());
+    assert_eq!(state.token1, token1.get_default_asset_id().to_string());
+    assert_eq!((state.reserve0, state.reserve1), (1000, 10000));
+    assert_eq!(state.total_supply, U256::from(liquidity + 1000));
+    assert_eq!(state.fee_bps, 30);
+    assert_eq!(state.k, U256::from(1000 * 10000));
+
+    /* one call instead of get_reserves, total_supply and the token getters */
+    let json = serde_json::to_string(&state).unwrap();
+    assert_eq!(serde_json::from_str::<funi_sdk::pair::PairStateView>(&json).unwrap(), state);
 }
 
 #[tokio::test]
@@ -192,26 +297,31 @@ async fn test_funi_swap() {
     /* record balance before swapping */
     let token0_asset_id = token0.clone().get_default_asset_id();
     let token1_asset_id = token1.clone().get_default_asset_id();
-    let token0_balance_before = pair.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_before = pair.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_before = pair.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_before = pair.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     /* transfer assets 0 to pair contract */
     let wallet = pair.deployer_wallet();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token0_asset_id,
-        amount0_in
-    ).await;
+        amount0_in,
+        pair.tx_config
+    ).await.unwrap();
 
     /* execute the swap */
-    pair.clone().call_swap(amount0_out, amount1_out, swapper).await;
+    pair.clone().call_swap(amount0_out, amount1_out, swapper).await.unwrap();
 
     /* record balance after swapping */
-    let token0_balance_after = pair.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_after = pair.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_after = pair.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_after = pair.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     assert_eq!(token0_balance_before, token0_balance_after + amount0_in);
     assert_eq!(token1_balance_before, token1_balance_after - amount1_out);
+
+    let sync = pair.last_sync_event().await.unwrap().unwrap();
+    assert_eq!(sync.reserve0, U256::from(amount0 + amount0_in));
+    assert_eq!(sync.reserve1, U256::from(amount1 - amount1_out));
 }
 
 /*
@@ -224,37 +334,38 @@ async fn setup_funi_pair(liquidity0: u64, liquidity1: u64) -> (
     ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
     u64
 ) {
-    let token0 = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
-    let token1 = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+    let (token0, token1) = new_sorted_tokens().await;
     let token0_asset_id = token0.clone().get_default_asset_id();
     let token1_asset_id = token1.clone().get_default_asset_id();
     let funi_pair_configurables = create_funi_pair_configurables(
         token0_asset_id,
         token1_asset_id
-    );
+    ).unwrap();
     
     let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
     let depositor = get_deployer_identity().await;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, liquidity0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, liquidity1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, liquidity0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, liquidity1).await.unwrap();
 
     /* transfer assets to pair contract */
     let wallet = pair.deployer_wallet();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token0_asset_id,
-        liquidity0
-    ).await;
+        liquidity0,
+        pair.tx_config
+    ).await.unwrap();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token1_asset_id,
-        liquidity1
-    ).await;
+        liquidity1,
+        pair.tx_config
+    ).await.unwrap();
 
     /* deploy initial liquidity */
-    let liquidity = pair.clone().call_mint(depositor).await;
+    let liquidity = pair.clone().call_mint(depositor).await.unwrap();
     (token0, token1, pair, liquidity)
 }
 
@@ -274,23 +385,25 @@ async fn mint_and_deploy_liquidity(
     let depositor = get_deployer_identity().await;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await.unwrap();
 
     /* transfer assets to pair contract */
     let wallet = pair.deployer_wallet();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token0_asset_id,
-        amount0
-    ).await;
+        amount0,
+        pair.tx_config
+    ).await.unwrap();
     wallet.transfer_to_contract(
         pair.contract_id(),
         token1_asset_id,
-        amount1
-    ).await;
+        amount1,
+        pair.tx_config
+    ).await.unwrap();
 
-    pair.clone().call_mint(depositor).await
+    pair.clone().call_mint(depositor).await.unwrap()
 }
 
 /*
@@ -304,6 +417,6 @@ async fn mint_coins(
     amount1: u64,
 ) {
     /* mint some token0 and token1 for the to address */
-    token0.clone().call_mint(to, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(to, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(to, DEFAULT_SUB_ID, amount1).await.unwrap();
 }
\ No newline at end of file
@@ -1 +1,8 @@
-pub mod liquidity;
\ No newline at end of file
+pub mod liquidity;
+pub mod invariants;
+pub mod differential;
+pub mod tokens;
+pub mod scripts;
+pub mod fee_tokens;
+pub mod position;
+pub mod limits;
\ No newline at end of file
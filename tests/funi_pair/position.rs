@@ -0,0 +1,92 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::pair::get_amount_out;
+use funi_sdk::position::Position;
+
+use fuels::prelude::*;
+
+#[tokio::test]
+async fn test_funi_get_underlying() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0_asset_id, token1_asset_id).unwrap()
+    ).await;
+    let to = pair.deployer_identity();
+
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, 1000).await.unwrap();
+    token1.clone().call_mint(to, DEFAULT_SUB_ID, 10000).await.unwrap();
+    let liquidity = pair.clone().call_add_liquidity_script(
+        token0_asset_id, 1000, token1_asset_id, 10000, 0, to
+    ).await.unwrap();
+
+    /* a view: nothing changes */
+    let underlying = pair.clone().call_get_underlying(liquidity).await.unwrap();
+    assert_eq!(underlying, (683, 6837));
+    assert_eq!(pair.clone().call_get_reserves().await.unwrap(), (1000, 10000));
+    assert_eq!(pair.clone().call_total_supply().await.unwrap(), liquidity + 1000);
+
+    /* burn gives the same */
+    assert_eq!(pair.clone().call_burn(to, liquidity).await.unwrap(), underlying);
+}
+
+#[tokio::test]
+async fn test_funi_position_impermanent_loss() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0_asset_id, token1_asset_id).unwrap()
+    ).await;
+    let to = pair.deployer_identity();
+
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, 10000).await.unwrap();
+    token1.clone().call_mint(to, DEFAULT_SUB_ID, 10000).await.unwrap();
+    pair.clone().call_add_liquidity_script(
+        token0_asset_id, 10000, token1_asset_id, 10000, 0, to
+    ).await.unwrap();
+
+    let entry = pair.wallet_position().await.unwrap();
+    assert_eq!(entry.liquidity, 9000);
+    assert_eq!((entry.amount0, entry.amount1), (9000, 9000));
+    assert!((entry.share() - 90.0).abs() < 1e-9);
+    assert!((entry.value().unwrap() - 18000.0).abs() < 1e-9);
+    assert_eq!(entry.impermanent_loss(&entry), Some(0.0));
+
+    /* the price of token0 drops to ~1/4 */
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, 10000).await.unwrap();
+    let amount_out = get_amount_out(10000, 10000, 10000).unwrap();
+    pair.clone().call_swap_script(token0_asset_id, 10000, amount_out, to).await.unwrap();
+
+    let current = pair.wallet_position().await.unwrap();
+    assert_eq!((current.reserve0, current.reserve1), (20000, 10000 - amount_out));
+    assert!((current.share() - 90.0).abs() < 1e-9);
+
+    /* 2 * sqrt(r) / (1 + r) - 1 = -20% for r = 1/4, a bit less with the fee earned */
+    let impermanent_loss = current.impermanent_loss(&entry).unwrap();
+    assert!(impermanent_loss < -0.19 && impermanent_loss > -0.2);
+}
+
+#[test]
+fn test_funi_position_empty_pool() {
+    let empty = Position {
+        liquidity: 0,
+        total_supply: 0,
+        amount0: 0,
+        amount1: 0,
+        reserve0: 0,
+        reserve1: 0,
+    };
+    assert_eq!(empty.share(), 0.0);
+    assert_eq!(empty.price(), None);
+    assert_eq!(empty.value(), None);
+
+    /* nothing held at entry */
+    let current = Position { reserve0: 1000, reserve1: 1000, ..empty };
+    assert_eq!(current.value(), Some(0.0));
+    assert_eq!(current.impermanent_loss(&empty), None);
+    assert_eq!(empty.impermanent_loss(&current), None);
+}
@@ -0,0 +1,126 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::pair::get_amount_out;
+
+use fuels::{
+    prelude::*,
+    types::Identity,
+};
+
+#[tokio::test]
+async fn test_funi_transfer_then_mint_can_be_front_run() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let pair = new_pair(&token0, &token1).await;
+    let depositor = pair.deployer_identity();
+    let attacker = Identity::Address(Address::from([7u8; 32]));
+
+    mint_tokens(&token0, &token1, depositor, 1000, 10000).await;
+    let wallet = pair.deployer_wallet();
+    wallet.transfer_to_contract(pair.contract_id(), token0.get_default_asset_id(), 1000, pair.tx_config).await.unwrap();
+    wallet.transfer_to_contract(pair.contract_id(), token1.get_default_asset_id(), 10000, pair.tx_config).await.unwrap();
+
+    /* anybody calling mint() between the transfers and the depositor's mint() gets the LP tokens */
+    let liquidity = pair.clone().call_mint(attacker).await.unwrap();
+    assert!(liquidity > 0);
+    let result = pair.clone().call_mint(depositor).await;
+    assert!(result.unwrap_err().is_revert(PairError::InsufficientLiquidity));
+}
+
+#[tokio::test]
+async fn test_funi_add_liquidity_script() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let pair = new_pair(&token0, &token1).await;
+    let depositor = pair.deployer_identity();
+    let attacker = Identity::Address(Address::from([7u8; 32]));
+
+    mint_tokens(&token0, &token1, depositor, 1000, 10000).await;
+
+    /* a minimum above what the deposit gives reverts the whole script, the tokens stay in the wallet */
+    let result = pair.clone().call_add_liquidity_script(
+        token0_asset_id, 1000, token1_asset_id, 10000, 10000, depositor
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientLiquidityMinted));
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), 1000);
+    assert_eq!(pair.deployer_balance(token1_asset_id).await.unwrap(), 10000);
+
+    /* sqrt(1000 * 10000) - MINIMUM_LIQUIDITY */
+    let liquidity = pair.clone().call_add_liquidity_script(
+        token0_asset_id, 1000, token1_asset_id, 10000, 2162, depositor
+    ).await.unwrap();
+    assert_eq!(liquidity, 2162);
+    assert_eq!(pair.deployer_balance(pair.get_default_asset_id()).await.unwrap(), liquidity);
+
+    /* the deposit is already in the reserves, nothing is left for a front-runner */
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    assert_eq!(reserve0, pair.get_contract_balance(token0_asset_id).await.unwrap());
+    assert_eq!(reserve1, pair.get_contract_balance(token1_asset_id).await.unwrap());
+    let result = pair.clone().call_mint(attacker).await;
+    assert!(result.unwrap_err().is_revert(PairError::InsufficientLiquidity));
+}
+
+#[tokio::test]
+async fn test_funi_swap_script() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let pair = new_pair(&token0, &token1).await;
+    let to = pair.deployer_identity();
+
+    mint_tokens(&token0, &token1, to, 10000, 100000).await;
+    pair.clone().call_add_liquidity_script(
+        token0_asset_id, 10000, token1_asset_id, 100000, 0, to
+    ).await.unwrap();
+
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, 1000).await.unwrap();
+    let expected = get_amount_out(1000, 10000, 100000).unwrap();
+
+    /* below the minimum the swap reverts and the input stays in the wallet */
+    let result = pair.clone().call_swap_script(token0_asset_id, 1000, expected + 1, to).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientOutputAmount));
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), 1000);
+
+    let amount_out = pair.clone().call_swap_script(token0_asset_id, 1000, expected, to).await.unwrap();
+    assert_eq!(amount_out, expected);
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), 0);
+    assert_eq!(pair.deployer_balance(token1_asset_id).await.unwrap(), expected);
+    assert_eq!(pair.clone().call_get_reserves().await.unwrap(), (11000, 100000 - expected));
+
+    /* and the other way round */
+    let expected = get_amount_out(expected, 100000 - expected, 11000).unwrap();
+    let amount_out = pair.clone().call_swap_script(token1_asset_id, amount_out, expected, to).await.unwrap();
+    assert_eq!(amount_out, expected);
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), expected);
+
+    /* an asset the pair doesn't trade */
+    let base_asset_id = *pair.deployer_wallet().provider().unwrap().base_asset_id();
+    let result = pair.clone().call_swap_script(base_asset_id, 1, 0, to).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InvalidAsset));
+}
+
+/*
+    ---- Helper functions
+*/
+
+async fn new_pair(
+    token0: &ContractInstance::<SRC20<WalletUnlocked>>,
+    token1: &ContractInstance::<SRC20<WalletUnlocked>>,
+) -> ContractInstance::<FuniSwapV2Pair<WalletUnlocked>> {
+    ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0.get_default_asset_id(), token1.get_default_asset_id()).unwrap()
+    ).await
+}
+
+async fn mint_tokens(
+    token0: &ContractInstance::<SRC20<WalletUnlocked>>,
+    token1: &ContractInstance::<SRC20<WalletUnlocked>>,
+    to: Identity,
+    amount0: u64,
+    amount1: u64,
+) {
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(to, DEFAULT_SUB_ID, amount1).await.unwrap();
+}
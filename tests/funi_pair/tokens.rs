@@ -0,0 +1,140 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::{Pair, Token};
+use funi_sdk::pair::sort_tokens;
+
+use fuels::{
+    prelude::*,
+    types::{
+        AssetId,
+        ContractId,
+        Identity,
+    },
+};
+
+#[tokio::test]
+async fn test_funi_pair_configurables_sorted() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+
+    /* tokens given in reverse order end up sorted */
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token1_asset_id,
+        token0_asset_id
+    ).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+
+    assert_eq!(pair.clone().call_token0().await.unwrap(), token0_asset_id);
+    assert_eq!(pair.clone().call_token1().await.unwrap(), token1_asset_id);
+    assert_eq!(sort_tokens(token1_asset_id, token0_asset_id), (token0_asset_id, token1_asset_id));
+}
+
+#[tokio::test]
+async fn test_funi_pair_rejects_unsorted_tokens() {
+    let (token0, token1) = new_sorted_tokens().await;
+
+    /* bypass create_funi_pair_configurables() to deploy the tokens in the wrong order */
+    let funi_pair_configurables = FuniSwapV2PairConfigurables::default()
+        .with_token0(token1.get_default_asset_id()).unwrap()
+        .with_token1(token0.get_default_asset_id()).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+
+    let result = pair.clone().call_mint(pair.deployer_identity()).await;
+    assert!(result.unwrap_err().is_revert(PairError::UnsortedAssetIds));
+}
+
+#[tokio::test]
+async fn test_funi_pair_rejects_identical_tokens() {
+    let token = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token.get_default_asset_id(),
+        token.get_default_asset_id()
+    ).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+
+    let result = pair.clone().call_swap(1, 0, pair.deployer_identity()).await;
+    assert!(result.unwrap_err().is_revert(PairError::UnsortedAssetIds));
+}
+
+#[tokio::test]
+async fn test_funi_pair_rejects_zero_token() {
+    /* zero is not the base asset of this chain, the pair rejects it even when allowed */
+    let wallet = get_wallet_with_base_asset(AssetId::from([1u8; 32])).await;
+    let token = Token::deploy(wallet.clone(), random_salt(), TxConfig::default()).await.unwrap();
+
+    let funi_pair_configurables = create_funi_pair_configurables(
+        AssetId::zeroed(),
+        token.get_default_asset_id()
+    ).unwrap();
+    for allow_base in [false, true] {
+        let pair = Pair::deploy_with_configurables(
+            wallet.clone(),
+            random_salt(),
+            funi_pair_configurables.clone().with_allow_base(allow_base).unwrap(),
+            TxConfig::default()
+        ).await.unwrap();
+
+        let result = pair.clone().call_mint(pair.deployer_identity()).await;
+        assert!(result.unwrap_err().is_revert(PairError::ZeroAssetId));
+    }
+}
+
+#[tokio::test]
+async fn test_funi_pair_allow_base_zero_base_asset() {
+    let wallet = get_wallet_with_base_asset(AssetId::zeroed()).await;
+    let to = Identity::Address(Address::from(wallet.address()));
+    let token = Token::deploy(wallet.clone(), random_salt(), TxConfig::default()).await.unwrap();
+    token.clone().call_mint_default(to, 1_000_000).await.unwrap();
+
+    /* the zero base asset is still an unset token for a pair deployed without allow_base */
+    let funi_pair_configurables = create_funi_pair_configurables(
+        AssetId::zeroed(),
+        token.get_default_asset_id()
+    ).unwrap();
+    let pair = Pair::deploy_with_configurables(
+        wallet.clone(),
+        random_salt(),
+        funi_pair_configurables,
+        TxConfig::default()
+    ).await.unwrap();
+    let result = pair.clone().call_mint(to).await;
+    assert!(result.unwrap_err().is_revert(PairError::ZeroAssetId));
+
+    /* and a pair of the base asset once allowed */
+    let pair = Pair::deploy_with_configurables(
+        wallet.clone(),
+        random_salt(),
+        create_funi_pair_base_configurables(token.get_default_asset_id(), AssetId::zeroed()).unwrap(),
+        TxConfig::default()
+    ).await.unwrap();
+    wallet.transfer_to_contract(pair.contract_id(), AssetId::zeroed(), 100_000, pair.tx_config).await.unwrap();
+    wallet.transfer_to_contract(pair.contract_id(), token.get_default_asset_id(), 100_000, pair.tx_config).await.unwrap();
+    assert_eq!(pair.clone().call_mint(to).await.unwrap(), 100_000 - 1000);
+}
+
+#[tokio::test]
+async fn test_funi_pair_getters() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id()
+    ).unwrap();
+
+    /* deployed on its own the factory is zero */
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables.clone()).await;
+    assert_eq!(pair.clone().call_factory().await.unwrap(), ContractId::zeroed());
+    assert_eq!(pair.clone().call_minimum_liquidity().await.unwrap(), 1000);
+
+    /* the factory id is set when the pair is deployed for a factory */
+    let factory_id = ContractId::from([7u8; 32]);
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        funi_pair_configurables.with_factory(factory_id).unwrap()
+    ).await;
+    assert_eq!(pair.clone().call_factory().await.unwrap(), factory_id);
+    assert_eq!(pair.clone().call_token0().await.unwrap(), token0.get_default_asset_id());
+    assert_eq!(pair.clone().call_token1().await.unwrap(), token1.get_default_asset_id());
+}
@@ -19,7 +19,7 @@ async fn test_funi_deposit() {
     let funi_router02_configurables = create_funi_router02_configurables(
         token0_asset_id,
         token1_asset_id
-    );
+    ).unwrap();
     
     let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(funi_router02_configurables).await;
     let depositor = router02.clone().deployer_identity();
@@ -27,23 +27,23 @@ async fn test_funi_deposit() {
     let amount1 = 10000;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await.unwrap();
 
     /* deposit tokens into the router02 contract */
     router02.clone().call_deposit(
         depositor,
         token0_asset_id,
         amount0
-    ).await;
+    ).await.unwrap();
     router02.clone().call_deposit(
         depositor,
         token1_asset_id,
         amount1
-    ).await;
+    ).await.unwrap();
 
     /* get the deposited amounts */
-    let (deposit0, deposit1) = router02.call_get_deposits(depositor).await;
+    let (deposit0, deposit1) = router02.call_get_deposits(depositor).await.unwrap();
 
     assert_eq!(deposit0, amount0);
     assert_eq!(deposit1, amount1);
@@ -58,7 +58,7 @@ async fn test_funi_withdraw() {
     let funi_router02_configurables = create_funi_router02_configurables(
         token0_asset_id,
         token1_asset_id
-    );
+    ).unwrap();
     
     let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(funi_router02_configurables).await;
     let depositor = router02.clone().deployer_identity();
@@ -66,31 +66,31 @@ async fn test_funi_withdraw() {
     let amount1 = 10000;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await.unwrap();
 
     /* record balance after minting and before deposit */
-    let token0_balance_before = router02.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_before = router02.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_before = router02.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_before = router02.clone().deployer_balance(token1_asset_id).await.unwrap();
 
      /* deposit tokens into the router02 contract */
      router02.clone().call_deposit(
         depositor,
         token0_asset_id,
         amount0
-    ).await;
+    ).await.unwrap();
     router02.clone().call_deposit(
         depositor,
         token1_asset_id,
         amount1
-    ).await;
+    ).await.unwrap();
 
     /* withdraw what was deposited */
-    router02.clone().call_withdraw(depositor).await;
+    router02.clone().call_withdraw(depositor).await.unwrap();
 
     /* record balance after withdraw */
-    let token0_balance_after = router02.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_after = router02.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_after = router02.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_after = router02.clone().deployer_balance(token1_asset_id).await.unwrap();
 
     assert_eq!(token0_balance_before, token0_balance_after);
     assert_eq!(token1_balance_before, token1_balance_after);
@@ -105,7 +105,7 @@ async fn test_funi_contract_deposit_balance() {
     let funi_router02_configurables = create_funi_router02_configurables(
         token0_asset_id,
         token1_asset_id
-    );
+    ).unwrap();
     
     let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(funi_router02_configurables).await;
     let depositor = get_deployer_identity().await;
@@ -113,27 +113,27 @@ async fn test_funi_contract_deposit_balance() {
     let amount1 = 10000;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await.unwrap();
 
     /* record balance after minting and before deposit */
-    let token0_balance_before = router02.clone().deployer_balance(token0_asset_id).await;
-    let token1_balance_before = router02.clone().deployer_balance(token1_asset_id).await;
+    let token0_balance_before = router02.clone().deployer_balance(token0_asset_id).await.unwrap();
+    let token1_balance_before = router02.clone().deployer_balance(token1_asset_id).await.unwrap();
 
      /* deposit tokens into the router02 contract */
      router02.clone().call_deposit(
         depositor,
         token0_asset_id,
         amount0
-    ).await;
+    ).await.unwrap();
     router02.clone().call_deposit(
         depositor,
         token1_asset_id,
         amount1
-    ).await;
+    ).await.unwrap();
 
-    let router02_token0_balance = router02.get_contract_balance(token0_asset_id).await;
-    let router02_token1_balance = router02.get_contract_balance(token1_asset_id).await;
+    let router02_token0_balance = router02.get_contract_balance(token0_asset_id).await.unwrap();
+    let router02_token1_balance = router02.get_contract_balance(token1_asset_id).await.unwrap();
 
     assert_eq!(amount0, router02_token0_balance);
     assert_eq!(amount1, router02_token1_balance);
@@ -148,7 +148,7 @@ async fn test_funi_contract_withdraw_balance() {
     let funi_router02_configurables = create_funi_router02_configurables(
         token0_asset_id,
         token1_asset_id
-    );
+    ).unwrap();
     
     let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(funi_router02_configurables).await;
     let depositor = get_deployer_identity().await;
@@ -156,31 +156,31 @@ async fn test_funi_contract_withdraw_balance() {
     let amount1 = 10000;
 
     /* mint some token0 and token1 for the depositor */
-    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await;
-    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await;
+    token0.clone().call_mint(depositor, DEFAULT_SUB_ID, amount0).await.unwrap();
+    token1.clone().call_mint(depositor, DEFAULT_SUB_ID, amount1).await.unwrap();
 
      /* deposit tokens into the router02 contract */
      router02.clone().call_deposit(
         depositor,
         token0_asset_id,
         amount0
-    ).await;
+    ).await.unwrap();
     router02.clone().call_deposit(
         depositor,
         token1_asset_id,
         amount1
-    ).await;
+    ).await.unwrap();
 
     /* record balance after minting and depositing */
-    let token0_balance_before = router02.get_contract_balance(token0_asset_id).await;
-    let token1_balance_before = router02.get_contract_balance(token1_asset_id).await;
+    let token0_balance_before = router02.get_contract_balance(token0_asset_id).await.unwrap();
+    let token1_balance_before = router02.get_contract_balance(token1_asset_id).await.unwrap();
 
     /* withdraw what was deposited */
-    router02.clone().call_withdraw(depositor).await;
+    router02.clone().call_withdraw(depositor).await.unwrap();
 
     /* record balance after withdrawing */
-    let token0_balance_after = router02.get_contract_balance(token0_asset_id).await;
-    let token1_balance_after = router02.get_contract_balance(token1_asset_id).await;
+    let token0_balance_after = router02.get_contract_balance(token0_asset_id).await.unwrap();
+    let token1_balance_after = router02.get_contract_balance(token1_asset_id).await.unwrap();
 
     assert_eq!(token0_balance_before, token0_balance_after + amount0);
     assert_eq!(token1_balance_before, token1_balance_after + amount1);
@@ -0,0 +1,155 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+
+use funi_sdk::pair::get_amount_out;
+
+use fuels::{
+    prelude::*,
+    types::{
+        AssetId,
+        Identity,
+    }
+};
+
+#[tokio::test]
+async fn test_funi_add_liquidity_eth() {
+    let (token, pair, router02) = setup_eth_pair().await;
+    let token_asset_id = token.get_default_asset_id();
+    let base_asset_id = router02.base_asset_id().unwrap();
+    let depositor = router02.deployer_identity();
+
+    /* only the token goes through the deposits ledger */
+    token.clone().call_mint(depositor, DEFAULT_SUB_ID, 150_000).await.unwrap();
+    router02.clone().call_deposit(depositor, token_asset_id, 150_000).await.unwrap();
+
+    /* first liquidity sets the price: 10 tokens per base asset */
+    let (amount_token, amount_eth, liquidity) = router02.clone().call_add_liquidity_eth(
+        pair.contract_id(), 10_000, 100_000, 0, 0, depositor
+    ).await.unwrap();
+    assert_eq!((amount_token, amount_eth), (100_000, 10_000));
+    assert_eq!(liquidity, pair.deployer_balance(pair.get_default_asset_id()).await.unwrap());
+    assert_eq!(get_reserves_for(&pair, token_asset_id).await, (100_000, 10_000));
+
+    /* 50000 tokens only need 5000 of the 10000 base asset sent, the rest is refunded */
+    let (amount_token, amount_eth, _liquidity) = router02.clone().call_add_liquidity_eth(
+        pair.contract_id(), 10_000, 50_000, 0, 0, depositor
+    ).await.unwrap();
+    assert_eq!((amount_token, amount_eth), (50_000, 5_000));
+    assert_eq!(get_reserves_for(&pair, token_asset_id).await, (150_000, 15_000));
+    assert_eq!(router02.get_contract_balance(base_asset_id).await.unwrap(), 0);
+    assert_eq!(router02.clone().call_get_deposits(depositor).await.unwrap(), (0, 0));
+
+    /* the refund would have to be below amount_eth_min */
+    token.clone().call_mint(depositor, DEFAULT_SUB_ID, 10_000).await.unwrap();
+    router02.clone().call_deposit(depositor, token_asset_id, 10_000).await.unwrap();
+    let result = router02.clone().call_add_liquidity_eth(
+        pair.contract_id(), 10_000, 10_000, 0, 5_000, depositor
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientBAmount));
+
+    /* more token than deposited */
+    let result = router02.clone().call_add_liquidity_eth(
+        pair.contract_id(), 10_000, 20_000, 0, 0, depositor
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientDeposit));
+}
+
+#[tokio::test]
+async fn test_funi_swap_exact_eth_for_tokens() {
+    let (token, pair, router02) = setup_eth_pair().await;
+    let token_asset_id = token.get_default_asset_id();
+    let to = router02.deployer_identity();
+    add_liquidity(&token, &pair, &router02, 100_000, 10_000).await;
+
+    let balance_before = router02.deployer_balance(token_asset_id).await.unwrap();
+    let expected = get_amount_out(1_000, 10_000, 100_000).unwrap();
+
+    /* asking for more than the pair gives */
+    let result = router02.clone().call_swap_exact_eth_for_tokens(
+        pair.contract_id(), 1_000, expected + 1, to
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientOutputAmount));
+
+    let amount_out = router02.clone().call_swap_exact_eth_for_tokens(
+        pair.contract_id(), 1_000, expected, to
+    ).await.unwrap();
+    assert_eq!(amount_out, expected);
+    assert_eq!(router02.deployer_balance(token_asset_id).await.unwrap(), balance_before + expected);
+    assert_eq!(get_reserves_for(&pair, token_asset_id).await, (100_000 - expected, 11_000));
+}
+
+#[tokio::test]
+async fn test_funi_swap_exact_tokens_for_eth() {
+    let (token, pair, router02) = setup_eth_pair().await;
+    let token_asset_id = token.get_default_asset_id();
+    let to = router02.deployer_identity();
+    add_liquidity(&token, &pair, &router02, 100_000, 10_000).await;
+
+    token.clone().call_mint(to, DEFAULT_SUB_ID, 10_000).await.unwrap();
+    let expected = get_amount_out(10_000, 100_000, 10_000).unwrap();
+
+    let result = router02.clone().call_swap_exact_tokens_for_eth(
+        pair.contract_id(), token_asset_id, 10_000, expected + 1, to
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientOutputAmount));
+
+    let amount_out = router02.clone().call_swap_exact_tokens_for_eth(
+        pair.contract_id(), token_asset_id, 10_000, expected, to
+    ).await.unwrap();
+    assert_eq!(amount_out, expected);
+    assert_eq!(get_reserves_for(&pair, token_asset_id).await, (110_000, 10_000 - expected));
+    assert_eq!(router02.deployer_balance(token_asset_id).await.unwrap(), 0);
+}
+
+/*
+    ---- Helper functions
+*/
+
+/*
+    New token, its pair with the base asset and a router for that pair.
+*/
+async fn setup_eth_pair() -> (
+    ContractInstance::<SRC20<WalletUnlocked>>,
+    ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>,
+) {
+    let token = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+    let base_asset_id = *token.deployer_wallet().provider().unwrap().base_asset_id();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_base_configurables(token.get_default_asset_id(), base_asset_id).unwrap()
+    ).await;
+    let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(
+        create_funi_router02_eth_configurables(token.get_default_asset_id(), base_asset_id, pair.contract_id()).unwrap()
+    ).await;
+    (token, pair, router02)
+}
+
+async fn add_liquidity(
+    token: &ContractInstance::<SRC20<WalletUnlocked>>,
+    pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    router02: &ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>,
+    amount_token: u64,
+    amount_eth: u64,
+) {
+    let depositor: Identity = router02.deployer_identity();
+    token.clone().call_mint(depositor, DEFAULT_SUB_ID, amount_token).await.unwrap();
+    router02.clone().call_deposit(depositor, token.get_default_asset_id(), amount_token).await.unwrap();
+    router02.clone().call_add_liquidity_eth(
+        pair.contract_id(), amount_eth, amount_token, 0, 0, depositor
+    ).await.unwrap();
+}
+
+/*
+    Reserves of the pair as (token reserve, base asset reserve)
+*/
+async fn get_reserves_for(
+    pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    token: AssetId,
+) -> (u64, u64) {
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    if pair.clone().call_token0().await.unwrap() == token {
+        (reserve0, reserve1)
+    } else {
+        (reserve1, reserve0)
+    }
+}
@@ -1 +1,3 @@
-pub mod deposits;
\ No newline at end of file
+pub mod deposits;
+pub mod eth;
+pub mod zap;
\ No newline at end of file
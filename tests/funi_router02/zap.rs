@@ -0,0 +1,130 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::pair::{
+    get_amount_out,
+    zap_swap_amount,
+};
+
+use fuels::prelude::*;
+
+const RESERVE0: u64 = 1_000_000;
+const RESERVE1: u64 = 4_000_000;
+
+#[tokio::test]
+async fn test_funi_zap_in() {
+    let (token0, token1, pair, router02) = setup_zap().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let to = router02.deployer_identity();
+    let amount_in = 100_000;
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, amount_in).await.unwrap();
+    let total_supply = pair.clone().call_total_supply().await.unwrap();
+
+    /* the minimum is checked after the mint */
+    let result = router02.clone().call_zap_in(
+        pair.contract_id(), token0_asset_id, amount_in, total_supply, to
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientLpOutput));
+
+    let lp_before = pair.deployer_balance(pair.get_default_asset_id()).await.unwrap();
+    let liquidity = router02.clone().call_zap_in(
+        pair.contract_id(), token0_asset_id, amount_in, 0, to
+    ).await.unwrap();
+    assert_eq!(pair.deployer_balance(pair.get_default_asset_id()).await.unwrap(), lp_before + liquidity);
+
+    /* swapped part and the rest were deposited in the reserve ratio */
+    let swap_in = zap_swap_amount(amount_in, RESERVE0);
+    let swap_out = get_amount_out(swap_in, RESERVE0, RESERVE1).unwrap();
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    assert_eq!((reserve0, reserve1), (RESERVE0 + amount_in, RESERVE1));
+
+    /* what the new LP tokens are worth against what was deposited */
+    let total_supply = pair.clone().call_total_supply().await.unwrap();
+    let share0 = (liquidity as u128 * reserve0 as u128 / total_supply as u128) as u64;
+    let share1 = (liquidity as u128 * reserve1 as u128 / total_supply as u128) as u64;
+    let dust0 = (amount_in - swap_in).saturating_sub(share0);
+    let dust1 = swap_out.saturating_sub(share1);
+    assert!(dust0 <= dust_bound(amount_in));
+    assert!(dust1 <= dust_bound(swap_out));
+
+    /* nothing stays in the router */
+    assert_eq!(router02.get_contract_balance(token0_asset_id).await.unwrap(), 0);
+    assert_eq!(router02.get_contract_balance(token1.get_default_asset_id()).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_funi_zap_out() {
+    let (token0, token1, pair, router02) = setup_zap().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let to = router02.deployer_identity();
+
+    /* zap in with token1, zap the LP tokens back out into token0 */
+    let amount_in = 400_000;
+    token1.clone().call_mint(to, DEFAULT_SUB_ID, amount_in).await.unwrap();
+    let liquidity = router02.clone().call_zap_in(
+        pair.contract_id(), token1.get_default_asset_id(), amount_in, 0, to
+    ).await.unwrap();
+
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    let total_supply = pair.clone().call_total_supply().await.unwrap();
+    let amount0 = (liquidity as u128 * reserve0 as u128 / total_supply as u128) as u64;
+    let amount1 = (liquidity as u128 * reserve1 as u128 / total_supply as u128) as u64;
+    let expected = amount0 + get_amount_out(amount1, reserve1 - amount1, reserve0 - amount0).unwrap();
+
+    let result = router02.clone().call_zap_out(
+        pair.contract_id(), liquidity, token0_asset_id, expected + 1, to
+    ).await;
+    assert!(result.unwrap_err().is_revert(RouterError::InsufficientOutputAmount));
+
+    let balance_before = pair.deployer_balance(token0_asset_id).await.unwrap();
+    let amount_out = router02.clone().call_zap_out(
+        pair.contract_id(), liquidity, token0_asset_id, expected, to
+    ).await.unwrap();
+    assert_eq!(amount_out, expected);
+    assert_eq!(pair.deployer_balance(token0_asset_id).await.unwrap(), balance_before + amount_out);
+
+    /* nothing stays in the router */
+    assert_eq!(router02.get_contract_balance(token0_asset_id).await.unwrap(), 0);
+    assert_eq!(router02.get_contract_balance(token1.get_default_asset_id()).await.unwrap(), 0);
+    assert_eq!(router02.get_contract_balance(pair.get_default_asset_id()).await.unwrap(), 0);
+}
+
+/*
+    ---- Helper functions
+*/
+
+/*
+    0.1% of the amount
+*/
+fn dust_bound(amount: u64) -> u64 {
+    amount / 1000
+}
+
+/*
+    Pair with RESERVE0 / RESERVE1 of liquidity and a router for it.
+*/
+async fn setup_zap() -> (
+    ContractInstance::<SRC20<WalletUnlocked>>,
+    ContractInstance::<SRC20<WalletUnlocked>>,
+    ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>,
+) {
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0_asset_id, token1_asset_id).unwrap()
+    ).await;
+    let router02 = ContractInstance::<FuniSwapV2Router02<WalletUnlocked>>::new_with_configurables(
+        create_funi_router02_pair_configurables(token0_asset_id, token1_asset_id, pair.contract_id()).unwrap()
+    ).await;
+
+    let to = pair.deployer_identity();
+    token0.clone().call_mint(to, DEFAULT_SUB_ID, RESERVE0).await.unwrap();
+    token1.clone().call_mint(to, DEFAULT_SUB_ID, RESERVE1).await.unwrap();
+    pair.clone().call_add_liquidity_script(
+        token0_asset_id, RESERVE0, token1_asset_id, RESERVE1, 0, to
+    ).await.unwrap();
+    (token0, token1, pair, router02)
+}
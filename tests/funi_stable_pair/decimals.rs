@@ -0,0 +1,19 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use fuels::prelude::*;
+
+#[tokio::test]
+async fn test_stable_pair_rejects_unset_decimals() {
+    let (token0, token1) = new_sorted_tokens().await;
+
+    /* the tokens alone, without their decimals */
+    let configurables = FuniSwapV2StablePairConfigurables::default()
+        .with_token0(token0.get_default_asset_id()).unwrap()
+        .with_token1(token1.get_default_asset_id()).unwrap();
+    let stable = ContractInstance::<FuniSwapV2StablePair<WalletUnlocked>>::new_with_configurables(configurables).await;
+
+    let result = stable.clone().call_mint(stable.deployer_identity()).await;
+    assert!(result.unwrap_err().is_revert(PairError::UnsetDecimals));
+}
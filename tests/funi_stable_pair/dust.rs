@@ -0,0 +1,70 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::stable_pair::stable_pair_configurables_for;
+
+use fuels::{
+    prelude::*,
+    types::{
+        ContractId,
+        Identity,
+    }
+};
+
+#[tokio::test]
+async fn test_stable_pair_dust_reserves_cannot_be_drained() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let to = get_deployer_identity().await;
+    let stable = ContractInstance::<FuniSwapV2StablePair<WalletUnlocked>>::new_with_configurables(
+        stable_pair_configurables_for(&token0, &token1).await.unwrap()
+    ).await;
+
+    /* dust reserves have a zero invariant, they are not accepted as the first deposit */
+    deposit(stable.contract_id(), &token0, to, 1000).await;
+    deposit(stable.contract_id(), &token1, to, 1000).await;
+    let result = stable.clone().call_mint(to).await;
+    assert!(result.unwrap_err().is_revert(PairError::InsufficientK));
+
+    /* 0.01 of each 9 decimals token is just above MINIMUM_K */
+    let reserve = 10_000_000;
+    deposit(stable.contract_id(), &token0, to, reserve - 1000).await;
+    deposit(stable.contract_id(), &token1, to, reserve - 1000).await;
+    stable.clone().call_mint(to).await.unwrap();
+    assert_eq!(stable.clone().call_get_reserves().await.unwrap(), (reserve, reserve));
+
+    /* the invariant of the small pool still rejects taking the reserves for a unit */
+    deposit(stable.contract_id(), &token0, to, 1).await;
+    for amount_out in [reserve - 1, reserve / 2, reserve / 100] {
+        let result = stable.clone().call_swap(0, amount_out, to).await;
+        assert!(result.unwrap_err().is_revert(PairError::KInvariantIncorrect));
+    }
+
+    /* a quoted swap goes through and the pool keeps its reserves */
+    let amount_out = stable.clone().call_get_amount_out(1000, token0.get_default_asset_id()).await.unwrap();
+    assert!(amount_out < 1000);
+    deposit(stable.contract_id(), &token0, to, 999).await;
+    stable.clone().call_swap(0, amount_out, to).await.unwrap();
+    assert_eq!(
+        stable.clone().call_get_reserves().await.unwrap(),
+        (reserve + 1000, reserve - amount_out)
+    );
+}
+
+/*
+    Mint tokens to the wallet and transfer them to the pair.
+*/
+async fn deposit(
+    pair: ContractId,
+    token: &ContractInstance::<SRC20<WalletUnlocked>>,
+    to: Identity,
+    amount: u64,
+) {
+    token.clone().call_mint(to, DEFAULT_SUB_ID, amount).await.unwrap();
+    token.deployer_wallet().transfer_to_contract(
+        pair,
+        token.get_default_asset_id(),
+        amount,
+        token.tx_config
+    ).await.unwrap();
+}
@@ -0,0 +1,3 @@
+pub mod slippage;
+pub mod decimals;
+pub mod dust;
\ No newline at end of file
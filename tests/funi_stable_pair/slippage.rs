@@ -0,0 +1,145 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::pair::get_amount_out;
+use funi_sdk::stable_pair::stable_pair_configurables_for;
+
+use fuels::{
+    prelude::*,
+    types::{
+        ContractId,
+        Identity,
+    }
+};
+
+#[tokio::test]
+async fn test_stable_pair_slippage_below_volatile() {
+    let liquidity = 1_000_000_000_000;
+    let amount_in = 100_000_000_000; /* 10% of the reserves */
+
+    let (token0, token1) = new_sorted_tokens().await;
+    let to = get_deployer_identity().await;
+
+    /* same reserves in both pairs */
+    let volatile = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0.get_default_asset_id(), token1.get_default_asset_id()).unwrap()
+    ).await;
+    deposit(volatile.contract_id(), &token0, to, liquidity).await;
+    deposit(volatile.contract_id(), &token1, to, liquidity).await;
+    volatile.clone().call_mint(to).await.unwrap();
+
+    let stable = ContractInstance::<FuniSwapV2StablePair<WalletUnlocked>>::new_with_configurables(
+        stable_pair_configurables_for(&token0, &token1).await.unwrap()
+    ).await;
+    deposit(stable.contract_id(), &token0, to, liquidity).await;
+    deposit(stable.contract_id(), &token1, to, liquidity).await;
+    stable.clone().call_mint(to).await.unwrap();
+
+    let volatile_out = get_amount_out(amount_in, liquidity, liquidity).unwrap();
+    let stable_out = stable.clone().call_get_amount_out(amount_in, token0.get_default_asset_id()).await.unwrap();
+
+    /* close to 1:1 on the stable curve, ~9% slippage on the volatile one */
+    assert!(volatile_out > amount_in * 90 / 100 && volatile_out < amount_in * 91 / 100);
+    assert!(stable_out > volatile_out);
+    assert!(stable_out > amount_in * 99 / 100);
+    assert!(stable_out < amount_in);
+
+    /* the quoted amount passes the invariant check of swap() */
+    deposit(stable.contract_id(), &token0, to, amount_in).await;
+    stable.clone().call_swap(0, stable_out, to).await.unwrap();
+    let (reserve0, reserve1) = stable.clone().call_get_reserves().await.unwrap();
+    assert_eq!(reserve0, liquidity + amount_in);
+    assert_eq!(reserve1, liquidity - stable_out);
+
+    /* asking for far more than the curve gives breaks the invariant */
+    deposit(stable.contract_id(), &token0, to, 1000).await;
+    let result = stable.clone().call_swap(0, liquidity / 2, to).await;
+    assert!(result.unwrap_err().is_revert(PairError::KInvariantIncorrect));
+}
+
+#[tokio::test]
+async fn test_stable_pair_decimal_normalisation() {
+    /* 1000000 units of a 6 decimals and a 9 decimals token in the pair, in whichever order they sort */
+    let token6 = new_token_with_decimals(6).await;
+    let token9 = new_token_with_decimals(9).await;
+    let liquidity6 = 1_000_000 * 1_000_000;
+    let liquidity9 = 1_000_000 * 1_000_000_000;
+    let to = get_deployer_identity().await;
+
+    let stable = ContractInstance::<FuniSwapV2StablePair<WalletUnlocked>>::new_with_configurables(
+        stable_pair_configurables_for(&token6, &token9).await.unwrap()
+    ).await;
+    deposit(stable.contract_id(), &token6, to, liquidity6).await;
+    deposit(stable.contract_id(), &token9, to, liquidity9).await;
+    stable.clone().call_mint(to).await.unwrap();
+
+    /* 1000 units of the 6 decimals token buy about 1000 units of the other one */
+    let amount_in = 1000 * 1_000_000;
+    let amount_out = stable.clone().call_get_amount_out(amount_in, token6.get_default_asset_id()).await.unwrap();
+    assert!(amount_out > 999 * 1_000_000_000);
+    assert!(amount_out < 1000 * 1_000_000_000);
+
+    /* and the other way round */
+    let amount_in = 1000 * 1_000_000_000;
+    let amount_out = stable.clone().call_get_amount_out(amount_in, token9.get_default_asset_id()).await.unwrap();
+    assert!(amount_out > 999 * 1_000_000);
+    assert!(amount_out < 1000 * 1_000_000);
+}
+
+#[tokio::test]
+async fn test_stable_pair_quote_bounds() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let to = get_deployer_identity().await;
+
+    /* an unbalanced pair, far from the peg */
+    let reserve0 = 1_000_000_000_000_000;
+    let reserve1 = 1_000_000;
+    let stable = ContractInstance::<FuniSwapV2StablePair<WalletUnlocked>>::new_with_configurables(
+        stable_pair_configurables_for(&token0, &token1).await.unwrap()
+    ).await;
+    deposit(stable.contract_id(), &token0, to, reserve0).await;
+    deposit(stable.contract_id(), &token1, to, reserve1).await;
+    stable.clone().call_mint(to).await.unwrap();
+
+    /* the output is rounded down: a single unit buys nothing */
+    let amount_out = stable.clone().call_get_amount_out(1, token0.get_default_asset_id()).await.unwrap();
+    assert_eq!(amount_out, 0);
+
+    /* huge inputs on either side quote less than the reserve instead of underflowing */
+    for (asset_in, reserve_out) in [
+        (token0.get_default_asset_id(), reserve1),
+        (token1.get_default_asset_id(), reserve0),
+    ] {
+        let amount_out = stable.clone().call_get_amount_out(1 << 62, asset_in).await.unwrap();
+        assert!(amount_out < reserve_out);
+    }
+}
+
+/*
+    ---- Helper functions
+*/
+
+async fn new_token_with_decimals(decimals: u8) -> ContractInstance::<SRC20<WalletUnlocked>> {
+    ContractInstance::<SRC20<WalletUnlocked>>::new_with_configurables(
+        create_src20_configurables("Token", "TKN", decimals).unwrap()
+    ).await
+}
+
+/*
+    Mint tokens to the wallet and transfer them to the pair.
+*/
+async fn deposit(
+    pair: ContractId,
+    token: &ContractInstance::<SRC20<WalletUnlocked>>,
+    to: Identity,
+    amount: u64,
+) {
+    token.clone().call_mint(to, DEFAULT_SUB_ID, amount).await.unwrap();
+    token.deployer_wallet().transfer_to_contract(
+        pair,
+        token.get_default_asset_id(),
+        amount,
+        token.tx_config
+    ).await.unwrap();
+}
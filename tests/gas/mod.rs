@@ -0,0 +1 @@
+pub mod report;
\ No newline at end of file
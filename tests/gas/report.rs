@@ -0,0 +1,157 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::gas::GAS_THRESHOLDS;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::gas::{
+    load_thresholds,
+    GasRegression,
+    GasReport,
+    GasSample,
+    GasStats,
+    GasThresholds,
+};
+use funi_sdk::instance::{
+    CallLog,
+    CallRecord,
+};
+
+use fuels::{
+    prelude::*,
+    types::ContractId,
+};
+
+fn sample(contract: &str, method: &str, gas_used: u64) -> GasSample {
+    GasSample {
+        contract: contract.to_string(),
+        method: method.to_string(),
+        gas_used,
+    }
+}
+
+#[test]
+fn test_gas_report_aggregation() {
+    let samples = vec![
+        sample("FuniSwapV2Pair", "mint", 100),
+        sample("FuniSwapV2Pair", "swap", 40),
+        sample("FuniSwapV2Pair", "mint", 300),
+        sample("FuniSwapV2Pair", "mint", 201),
+        sample("SRC20", "mint", 7),
+    ];
+    let report = GasReport::from_samples(&samples);
+
+    /* grouped per contract and method, the mean is rounded down */
+    assert_eq!(report.methods.len(), 3);
+    assert_eq!(
+        report.methods["FuniSwapV2Pair::mint"],
+        GasStats { calls: 3, min: 100, max: 300, mean: 200 }
+    );
+    assert_eq!(
+        report.methods["FuniSwapV2Pair::swap"],
+        GasStats { calls: 1, min: 40, max: 40, mean: 40 }
+    );
+    assert_eq!(
+        report.methods["SRC20::mint"],
+        GasStats { calls: 1, min: 7, max: 7, mean: 7 }
+    );
+
+    assert_eq!(GasReport::from_samples(&[]), GasReport::default());
+}
+
+#[test]
+fn test_gas_report_regressions() {
+    let report = GasReport::from_samples(&[
+        sample("FuniSwapV2Pair", "mint", 100),
+        sample("FuniSwapV2Pair", "mint", 300),
+        sample("FuniSwapV2Pair", "swap", 40),
+        sample("SRC20", "mint", 7),
+    ]);
+    let thresholds = GasThresholds::from([
+        /* only the max is compared */
+        ("FuniSwapV2Pair::mint".to_string(), 200),
+        /* equal to the threshold is fine */
+        ("FuniSwapV2Pair::swap".to_string(), 40),
+        /* thresholds of methods not called are ignored */
+        ("FuniSwapV2Pair::burn".to_string(), 1),
+    ]);
+
+    assert_eq!(report.regressions(&thresholds), vec![GasRegression {
+        method: "FuniSwapV2Pair::mint".to_string(),
+        max: 300,
+        threshold: 200,
+    }]);
+    assert!(report.regressions(&GasThresholds::new()).is_empty());
+}
+
+#[test]
+fn test_gas_report_from_sink() {
+    let path = std::env::temp_dir().join("funi-test-gas-sink.jsonl");
+    std::fs::write(&path, "").unwrap();
+    let samples = vec![
+        sample("FuniSwapV2Pair", "mint", 100),
+        sample("FuniSwapV2Pair", "mint", 300),
+    ];
+    let lines: Vec<String> = samples.iter().map(|sample| serde_json::to_string(sample).unwrap()).collect();
+    std::fs::write(&path, lines.join("\n") + "\n\n").unwrap();
+
+    assert_eq!(GasReport::from_sink(&path).unwrap(), GasReport::from_samples(&samples));
+}
+
+#[test]
+fn test_call_log_keeps_sink_error() {
+    let path = std::env::temp_dir().join("funi-test-gas-sink-error.jsonl");
+    let call_log = CallLog::with_sink(&path).unwrap();
+    let record = CallRecord {
+        contract_id: ContractId::zeroed(),
+        contract: "FuniSwapV2Pair",
+        method: "mint",
+        tx_id: None,
+        gas_used: 100,
+    };
+    call_log.push(record.clone());
+    assert_eq!(call_log.last_error(), None);
+
+    /* the sink can no longer be opened, the call is still recorded */
+    std::fs::remove_file(&path).unwrap();
+    std::fs::create_dir(&path).unwrap();
+    call_log.push(record);
+    assert!(call_log.last_error().is_some());
+    assert_eq!(call_log.records().len(), 2);
+    std::fs::remove_dir(&path).unwrap();
+}
+
+/*
+    Runs a mint, swap and burn and fails when one of them is above its threshold
+    in gas_thresholds.json.
+*/
+#[tokio::test]
+async fn test_gas_within_thresholds() {
+    let thresholds = load_thresholds(GAS_THRESHOLDS).unwrap();
+    let call_log = CallLog::default();
+    let (token0, token1) = new_sorted_tokens().await;
+    let (token0, token1) = (token0.with_call_log(call_log.clone()), token1.with_call_log(call_log.clone()));
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(
+        create_funi_pair_configurables(token0.get_default_asset_id(), token1.get_default_asset_id()).unwrap()
+    ).await.with_call_log(call_log.clone());
+    let to = pair.deployer_identity();
+    let wallet = pair.deployer_wallet();
+
+    for token in [&token0, &token1] {
+        token.clone().call_mint(to, DEFAULT_SUB_ID, 1_000_000).await.unwrap();
+        wallet.transfer_to_contract(pair.contract_id(), token.get_default_asset_id(), 100_000, pair.tx_config).await.unwrap();
+    }
+    let liquidity = pair.clone().call_mint(to).await.unwrap();
+
+    wallet.transfer_to_contract(pair.contract_id(), token0.get_default_asset_id(), 1000, pair.tx_config).await.unwrap();
+    let amount_out = funi_sdk::pair::get_amount_out(1000, 100_000, 100_000).unwrap();
+    pair.clone().call_swap(0, amount_out, to).await.unwrap();
+
+    pair.clone().call_burn(to, liquidity / 2).await.unwrap();
+
+    let report = GasReport::from_records(&call_log.records());
+    for method in thresholds.keys() {
+        assert!(report.methods.contains_key(method), "{} was not called", method);
+    }
+    let regressions = report.regressions(&thresholds);
+    assert!(regressions.is_empty(), "gas regressions: {:?}", regressions);
+}
@@ -1,4 +1,10 @@
 mod utils;
 mod src20;
 mod funi_pair;
+mod funi_stable_pair;
 mod funi_router02;
+mod funi_factory;
+mod manifest;
+mod indexer;
+mod cli;
+mod gas;
\ No newline at end of file
@@ -0,0 +1,211 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::indexer::{
+    read_json_lines,
+    PairIndexer,
+    PoolEvent,
+    PoolState,
+};
+use funi_sdk::pair::get_amount_out;
+
+use fuels::{
+    prelude::*,
+    tx::{
+        Receipt,
+        TxId,
+    },
+    types::{
+        tx_status::TxStatus,
+        Identity,
+    },
+};
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_indexer_rebuilds_reserves() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id()
+    ).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+    let to = pair.deployer_identity();
+
+    /* scripted session: mint, swap both ways, mint again, donate, burn half of the LP tokens */
+    deposit(&pair, &token0, to, 10000).await;
+    deposit(&pair, &token1, to, 100000).await;
+    pair.clone().call_mint(to).await.unwrap();
+
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    deposit(&pair, &token0, to, 1000).await;
+    pair.clone().call_swap(0, get_amount_out(1000, reserve0, reserve1).unwrap(), to).await.unwrap();
+
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    deposit(&pair, &token1, to, 5000).await;
+    pair.clone().call_swap(get_amount_out(5000, reserve1, reserve0).unwrap(), 0, to).await.unwrap();
+
+    deposit(&pair, &token0, to, 2000).await;
+    deposit(&pair, &token1, to, 20000).await;
+    pair.clone().call_mint(to).await.unwrap();
+
+    /* a donation is only reflected in the reserves through the SyncEvent of the burn */
+    deposit(&pair, &token0, to, 500).await;
+    let lp_balance = pair.deployer_balance(pair.get_default_asset_id()).await.unwrap();
+    pair.clone().call_burn(to, lp_balance / 2).await.unwrap();
+
+    let mut indexer = PairIndexer::new(&pair).unwrap();
+    assert_eq!(indexer.sync().await.unwrap(), 5);
+
+    let events: Vec<&PoolEvent> = indexer.snapshots().iter().map(|snapshot| &snapshot.event).collect();
+    assert!(matches!(
+        events.as_slice(),
+        [
+            PoolEvent::Mint { .. },
+            PoolEvent::Swap { .. },
+            PoolEvent::Swap { .. },
+            PoolEvent::Mint { .. },
+            PoolEvent::Burn { .. },
+        ]
+    ));
+
+    /* rebuilt state matches the contract */
+    let state = indexer.state();
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    assert_eq!((state.reserve0, state.reserve1), (reserve0 as u128, reserve1 as u128));
+    let total_supply = pair.clone().call_total_supply().await.unwrap();
+    assert_eq!(state.total_supply, total_supply as u128);
+    assert_eq!((state.volume0, state.volume1), (1000, 5000));
+
+    /* nothing new on the next sync */
+    assert_eq!(indexer.sync().await.unwrap(), 0);
+
+    /* the time series survives a round trip through JSON lines */
+    let path = std::env::temp_dir().join(format!("funi-indexer-{}.jsonl", pair.contract_id()));
+    indexer.write_json_lines(&path).unwrap();
+    assert_eq!(read_json_lines(&path).unwrap(), indexer.snapshots());
+}
+
+#[tokio::test]
+async fn test_indexer_resumes_from_checkpoint() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id()
+    ).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+    let to = pair.deployer_identity();
+    let path = std::env::temp_dir().join(format!("funi-indexer-checkpoint-{}.json", pair.contract_id()));
+    let _ = std::fs::remove_file(&path);
+
+    deposit(&pair, &token0, to, 10000).await;
+    deposit(&pair, &token1, to, 100000).await;
+    pair.clone().call_mint(to).await.unwrap();
+
+    let mut indexer = PairIndexer::new(&pair).unwrap().with_checkpoint(&path).unwrap();
+    assert_eq!(indexer.sync().await.unwrap(), 1);
+    let block_height = indexer.block_height().unwrap();
+
+    /* a new indexer starts from the saved block and state, not from genesis */
+    let mut resumed = PairIndexer::new(&pair).unwrap().with_checkpoint(&path).unwrap();
+    assert_eq!(resumed.block_height(), Some(block_height));
+    assert_eq!(resumed.state(), indexer.state());
+    assert_eq!(resumed.sync().await.unwrap(), 0);
+
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    deposit(&pair, &token0, to, 1000).await;
+    pair.clone().call_swap(0, get_amount_out(1000, reserve0, reserve1).unwrap(), to).await.unwrap();
+
+    let mut resumed = PairIndexer::new(&pair).unwrap().with_checkpoint(&path).unwrap();
+    assert_eq!(resumed.sync().await.unwrap(), 1);
+    assert!(matches!(resumed.snapshots(), [snapshot] if matches!(snapshot.event, PoolEvent::Swap { .. })));
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    assert_eq!((resumed.state().reserve0, resumed.state().reserve1), (reserve0 as u128, reserve1 as u128));
+    assert_eq!(resumed.state().volume0, 1000);
+}
+
+#[tokio::test]
+async fn test_indexer_rolls_back_failed_block() {
+    let (token0, token1) = new_sorted_tokens().await;
+    let funi_pair_configurables = create_funi_pair_configurables(
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id()
+    ).unwrap();
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::new_with_configurables(funi_pair_configurables).await;
+    let to = pair.deployer_identity();
+
+    deposit(&pair, &token0, to, 10000).await;
+    deposit(&pair, &token1, to, 100000).await;
+    pair.clone().call_mint(to).await.unwrap();
+    let first_mint = last_receipts(&pair).await;
+
+    deposit(&pair, &token0, to, 10000).await;
+    deposit(&pair, &token1, to, 100000).await;
+    pair.clone().call_mint(to).await.unwrap();
+
+    /* takes more token1 than the first mint alone provided */
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    deposit(&pair, &token0, to, 60000).await;
+    pair.clone().call_swap(0, get_amount_out(60000, reserve0, reserve1).unwrap(), to).await.unwrap();
+    let swap = last_receipts(&pair).await;
+
+    /* the second mint is missing from the block, the swap fails after the first mint applied */
+    let mut indexer = PairIndexer::new(&pair).unwrap();
+    assert!(indexer.apply_block(1, None, &[first_mint.clone(), swap]).is_err());
+    assert_eq!(indexer.state(), PoolState::default());
+    assert!(indexer.snapshots().is_empty());
+    assert_eq!(indexer.block_height(), None);
+
+    indexer.apply_block(1, None, &[first_mint]).unwrap();
+    assert_eq!(indexer.snapshots().len(), 1);
+    assert_eq!(indexer.block_height(), Some(1));
+    assert_eq!((indexer.state().reserve0, indexer.state().reserve1), (10000, 100000));
+}
+
+#[test]
+fn test_pool_state_rejects_underflow() {
+    let mut state = PoolState { reserve0: 1000, reserve1: 1000, ..PoolState::default() };
+
+    /* more out than the reserves hold, the state is left as it was */
+    let swap = PoolEvent::Swap { amount0_in: 10, amount1_in: 0, amount0_out: 0, amount1_out: 1001 };
+    assert!(state.apply(&swap, 0, 0).is_err());
+    let burn = PoolEvent::Burn { amount0: 1, amount1: 1 };
+    assert!(state.apply(&burn, 0, 1).is_err());
+    assert_eq!(state, PoolState { reserve0: 1000, reserve1: 1000, ..PoolState::default() });
+
+    let swap = PoolEvent::Swap { amount0_in: 10, amount1_in: 0, amount0_out: 0, amount1_out: 9 };
+    state.apply(&swap, 0, 0).unwrap();
+    assert_eq!((state.reserve0, state.reserve1, state.volume0), (1010, 991, 10));
+}
+
+/*
+    Mint tokens to the wallet and transfer them to the pair.
+*/
+async fn deposit(
+    pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>,
+    token: &ContractInstance::<SRC20<WalletUnlocked>>,
+    to: Identity,
+    amount: u64,
+) {
+    token.clone().call_mint(to, DEFAULT_SUB_ID, amount).await.unwrap();
+    pair.deployer_wallet().transfer_to_contract(
+        pair.contract_id(),
+        token.get_default_asset_id(),
+        amount,
+        pair.tx_config
+    ).await.unwrap();
+}
+
+/*
+    Tx id and receipts of the last call made to the pair.
+*/
+async fn last_receipts(pair: &ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>) -> (String, Vec<Receipt>) {
+    let tx_id = pair.last_call().unwrap().tx_id.unwrap();
+    let provider = pair.deployer_wallet().provider().unwrap().clone();
+    let response = provider.get_transaction_by_id(&TxId::from_str(&tx_id).unwrap()).await.unwrap().unwrap();
+    match response.status {
+        TxStatus::Success { receipts } => (tx_id, receipts),
+        status => panic!("unexpected status {:?}", status),
+    }
+}
@@ -0,0 +1,226 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+use crate::utils::src20::new_sorted_tokens;
+
+use funi_sdk::Error;
+use funi_sdk::manifest::{
+    bytecode_hash,
+    Deployment,
+    Manifest,
+};
+
+use fuels::{
+    prelude::*,
+    types::ContractId,
+};
+
+use std::collections::BTreeMap;
+
+#[tokio::test]
+async fn test_manifest_reattach_token() {
+    let (_provider, wallet, salt) = get_wallet_provider_salt_for("TKA").await;
+    let path = Manifest::path_for(std::env::temp_dir(), "funi-test-reattach-token");
+    let mut manifest = Manifest::new(FUEL_NETWORK);
+
+    /* first call deploys and records the token */
+    let token = manifest.token_or_deploy(
+        "TKA",
+        "TokeA",
+        "TKA",
+        9,
+        wallet.clone(),
+        salt,
+        TxConfig::default()
+    ).await.unwrap();
+    manifest.save(&path).unwrap();
+
+    let deployment = manifest.tokens.get("TKA").unwrap();
+    assert_eq!(deployment.contract_id, token.contract_id().to_string());
+    assert_eq!(deployment.salt, salt.to_string());
+    assert_eq!(deployment.configurables.get("symbol"), Some(&String::from("TKA")));
+
+    /* loading the manifest attaches to the same contract instead of deploying */
+    let mut loaded = Manifest::load(&path).unwrap();
+    assert_eq!(manifest, loaded);
+
+    let reattached = loaded.token_or_deploy(
+        "TKA",
+        "TokeA",
+        "TKA",
+        9,
+        wallet.clone(),
+        get_wallet_provider_salt().await.2,
+        TxConfig::default()
+    ).await.unwrap();
+    assert_eq!(token.contract_id(), reattached.contract_id());
+    assert_eq!(manifest, loaded);
+
+    let symbol = reattached.clone().call_symbol_def_asset_id().await.unwrap();
+    assert_eq!(symbol, Some(String::from("TKA")));
+}
+
+#[tokio::test]
+async fn test_manifest_reattach_pair() {
+    let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let mut manifest = Manifest::new(FUEL_NETWORK);
+
+    let pair = manifest.pair_or_deploy(
+        "pair",
+        token0_asset_id,
+        token1_asset_id,
+        None,
+        wallet.clone(),
+        salt,
+        TxConfig::default()
+    ).await.unwrap();
+
+    let reattached = manifest.pair("pair", wallet, TxConfig::default())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(pair.contract_id(), reattached.contract_id());
+    assert_eq!(
+        manifest.pairs.get("pair").unwrap().configurables.get("token0"),
+        Some(&token0_asset_id.to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_manifest_router_for_pair() {
+    let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+    let (token0, token1) = new_sorted_tokens().await;
+    let token0_asset_id = token0.get_default_asset_id();
+    let token1_asset_id = token1.get_default_asset_id();
+    let to = token0.deployer_identity();
+    let mut manifest = Manifest::new(FUEL_NETWORK);
+
+    let pair = manifest.pair_or_deploy(
+        "pair",
+        token0_asset_id,
+        token1_asset_id,
+        None,
+        wallet.clone(),
+        salt,
+        TxConfig::default()
+    ).await.unwrap();
+    let router = manifest.router_or_deploy(
+        "router",
+        token0_asset_id,
+        token1_asset_id,
+        pair.contract_id(),
+        wallet.clone(),
+        get_wallet_provider_salt().await.2,
+        TxConfig::default()
+    ).await.unwrap();
+    assert_eq!(
+        manifest.routers.get("router").unwrap().configurables.get("pair"),
+        Some(&pair.contract_id().to_string())
+    );
+
+    /* the router trades through the recorded pair */
+    for token in [&token0, &token1] {
+        token.clone().call_mint(to, DEFAULT_SUB_ID, 200_000).await.unwrap();
+        wallet.transfer_to_contract(pair.contract_id(), token.get_default_asset_id(), 100_000, pair.tx_config).await.unwrap();
+    }
+    pair.clone().call_mint(to).await.unwrap();
+    let liquidity = router.call_zap_in(
+        pair.contract_id(),
+        token0_asset_id,
+        1000,
+        1,
+        to
+    ).await.unwrap();
+    assert!(liquidity > 0);
+    let (reserve0, reserve1) = pair.clone().call_get_reserves().await.unwrap();
+    assert!(reserve0 > 100_000);
+    assert_eq!(reserve1, 100_000);
+}
+
+#[tokio::test]
+async fn test_manifest_not_deployed() {
+    let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+    let mut manifest = Manifest::new(FUEL_NETWORK);
+    manifest.tokens.insert(
+        String::from("missing"),
+        Deployment {
+            contract_id: ContractId::zeroed().to_string(),
+            salt: salt.to_string(),
+            bytecode_hash: bytecode_hash(SRC20_BINARY).unwrap(),
+            configurables: BTreeMap::new(),
+        }
+    );
+
+    let result = manifest.token("missing", wallet, TxConfig::default()).await;
+    assert!(matches!(result, Err(Error::NotDeployed(_))));
+}
+
+#[tokio::test]
+async fn test_manifest_rejects_other_bytecode() {
+    let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+    let mut manifest = Manifest::new(FUEL_NETWORK);
+    manifest.token_or_deploy("TKA", "TokeA", "TKA", 9, wallet.clone(), salt, TxConfig::default()).await.unwrap();
+
+    /* a contract built from another binary is not reattached */
+    manifest.tokens.get_mut("TKA").unwrap().bytecode_hash = String::from("0x00");
+    let result = manifest.token("TKA", wallet, TxConfig::default()).await;
+    assert!(matches!(result, Err(Error::InvalidManifest(_))));
+}
+
+#[tokio::test]
+async fn test_manifest_rejects_other_configurables() {
+    let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+    let (token0, token1) = new_sorted_tokens().await;
+    let mut manifest = Manifest::new(FUEL_NETWORK);
+    manifest.token_or_deploy("TKA", "TokeA", "TKA", 9, wallet.clone(), salt, TxConfig::default()).await.unwrap();
+    manifest.pair_or_deploy(
+        "pair",
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id(),
+        None,
+        wallet.clone(),
+        get_wallet_provider_salt().await.2,
+        TxConfig::default()
+    ).await.unwrap();
+    let recorded = manifest.clone();
+
+    /* the same label asked with other configurables is neither reattached nor redeployed */
+    let result = manifest.token_or_deploy("TKA", "TokeA", "TKA", 6, wallet.clone(), salt, TxConfig::default()).await;
+    assert!(matches!(result, Err(Error::InvalidManifest(_))));
+
+    let result = manifest.pair_or_deploy(
+        "pair",
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id(),
+        Some(ContractId::zeroed()),
+        wallet.clone(),
+        get_wallet_provider_salt().await.2,
+        TxConfig::default()
+    ).await;
+    assert!(matches!(result, Err(Error::InvalidManifest(_))));
+
+    /* token order doesn't matter, the pair records them sorted */
+    manifest.pair_or_deploy(
+        "pair",
+        token1.get_default_asset_id(),
+        token0.get_default_asset_id(),
+        None,
+        wallet,
+        get_wallet_provider_salt().await.2,
+        TxConfig::default()
+    ).await.unwrap();
+    assert_eq!(manifest, recorded);
+}
+
+#[tokio::test]
+async fn test_manifest_rejects_other_network() {
+    let path = Manifest::path_for(std::env::temp_dir(), "funi-test-other-network");
+    Manifest::new("testnet.fuel.network").save(&path).unwrap();
+
+    let result = Manifest::load_or_new(&path, FUEL_NETWORK);
+    assert!(matches!(result, Err(Error::InvalidManifest(_))));
+    assert_eq!(Manifest::load_or_new(&path, "testnet.fuel.network").unwrap().network, "testnet.fuel.network");
+}
@@ -0,0 +1,2 @@
+pub mod deployments;
+pub mod salts;
\ No newline at end of file
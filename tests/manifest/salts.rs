@@ -0,0 +1,65 @@
+use crate::utils::setup::*;
+use crate::utils::instance::*;
+
+use funi_sdk::utils::deterministic_salt;
+
+use fuels::prelude::*;
+
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_deterministic_salt_snapshot() {
+    /* sha256("funi:pair") */
+    let expected = Salt::from_str(
+        "8575c8a45653b532e1450c1b5e67a692c7baac893b4110320c7405436514ae34"
+    ).unwrap();
+
+    assert_eq!(deterministic_salt("funi", "pair"), expected);
+    assert_eq!(salt_for(Some("funi"), "pair"), expected);
+    assert_ne!(deterministic_salt("funi", "token0"), expected);
+    assert_ne!(deterministic_salt("other", "pair"), expected);
+}
+
+#[tokio::test]
+async fn test_deterministic_salt_contract_id() {
+    /* a fresh seed per run, the node keeps contracts of previous runs */
+    let seed = random_salt().to_string();
+    let token0 = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+    let token1 = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
+    let configurables = create_funi_pair_configurables(
+        token0.get_default_asset_id(),
+        token1.get_default_asset_id()
+    ).unwrap();
+
+    /* contract and LP asset IDs are known before deploying */
+    let salt = deterministic_salt(&seed, "pair");
+    let predicted_id = predict_contract_id(
+        FUNI_PAIR_BINARY,
+        salt,
+        configurables.clone()
+    ).unwrap();
+
+    let (_provider, wallet, _salt) = get_wallet_provider_salt().await;
+    let pair = ContractInstance::<FuniSwapV2Pair<WalletUnlocked>>::deploy_with_configurables(
+        wallet,
+        deterministic_salt(&seed, "pair"),
+        configurables,
+        TxConfig::default()
+    ).await.unwrap();
+
+    assert_eq!(predicted_id, pair.contract_id());
+    assert_eq!(
+        get_default_asset_id(predicted_id),
+        pair.get_default_asset_id()
+    );
+}
+
+#[tokio::test]
+async fn test_salt_for_test_name() {
+    /* the label is prefixed with the test path, the seed is shared by the whole run */
+    let (_provider, _wallet, salt) = get_wallet_provider_salt_for("pair").await;
+    let expected = deterministic_salt(&salt_seed(), "manifest::salts::test_salt_for_test_name:pair");
+    assert_eq!(salt, expected);
+    assert_eq!(get_wallet_provider_salt_for("pair").await.2, expected);
+    assert_ne!(get_wallet_provider_salt_for("token0").await.2, expected);
+}
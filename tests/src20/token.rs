@@ -13,7 +13,7 @@ async fn test_src20_name() {
     let token = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
     let mut result = token.clone().call_name(
         get_default_asset_id(token.contract_id())
-    ).await;
+    ).await.unwrap();
     assert_eq!(
         result, 
         Some(String::from("Token"))
@@ -21,7 +21,7 @@ async fn test_src20_name() {
 
     result = token.clone()
         .call_name_def_asset_id()
-        .await;
+        .await.unwrap();
 
     assert_eq!(
         result, 
@@ -34,7 +34,7 @@ async fn test_src20_name_incorrect_asset_id() {
     let token = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
     let result = token.clone().call_name(
         AssetId::zeroed()
-    ).await;
+    ).await.unwrap();
     assert_eq!(
         result, 
         None
@@ -46,7 +46,7 @@ async fn test_src20_symbol() {
     let token = ContractInstance::<SRC20<WalletUnlocked>>::new().await;
     let mut result = token.clone().call_symbol(
         get_default_asset_id(token.contract_id())
-    ).await;
+    ).await.unwrap();
     assert_eq!(
         result, 
         Some(String::from("TKN"))
@@ -54,7 +54,7 @@ async fn test_src20_symbol() {
 
     result = token.clone()
         .call_symbol_def_asset_id()
-        .await;
+        .await.unwrap();
 
     assert_eq!(
         result, 
@@ -72,20 +72,20 @@ async fn test_src20_mint() {
             get_default_asset_id(
                 token.contract_id()
             )
-        ).await;
+        ).await.unwrap();
 
     token.clone().call_mint(
         token.clone().deployer_identity(),
         DEFAULT_SUB_ID,
         amount
-    ).await;
+    ).await.unwrap();
 
     let balance_after = token.clone()
         .deployer_balance(
             get_default_asset_id(
                 token.contract_id()
             )
-        ).await;
+        ).await.unwrap();
 
     assert_eq!(
         balance_before + amount, 
@@ -102,26 +102,26 @@ async fn test_src20_burn() {
         token.clone().deployer_identity(),
         DEFAULT_SUB_ID,
         amount
-    ).await;
+    ).await.unwrap();
 
     let balance_before = token.clone()
         .deployer_balance(
             get_default_asset_id(
                 token.contract_id()
             )
-        ).await;
+        ).await.unwrap();
 
     token.clone().call_burn(
         DEFAULT_SUB_ID,
         amount
-    ).await;
+    ).await.unwrap();
 
     let balance_after = token.clone()
         .deployer_balance(
             get_default_asset_id(
                 token.contract_id()
             )
-        ).await;
+        ).await.unwrap();
     
     assert_eq!(
         balance_before, 
@@ -136,17 +136,17 @@ async fn test_src20_total_supply() {
 
     let total_supply_before = token.clone()
         .call_total_supply_def_asset_id()
-        .await;
+        .await.unwrap();
 
     token.clone().call_mint(
         token.clone().deployer_identity(),
         DEFAULT_SUB_ID,
         amount
-    ).await;
+    ).await.unwrap();
 
     let total_supply_after = token.clone()
         .call_total_supply_def_asset_id()
-        .await;
+        .await.unwrap();
 
     assert_eq!(
         total_supply_before + amount,
@@ -159,12 +159,12 @@ async fn test_src20_configurables() {
     let name = "SRC20";
     let symbol = "S20";
     let decimals = 10;
-    let configurables = create_src20_configurables(name, symbol, decimals);
+    let configurables = create_src20_configurables(name, symbol, decimals).unwrap();
     let token = ContractInstance::<SRC20<WalletUnlocked>>::new_with_configurables(configurables).await;
 
     let result_symbol = token.clone().call_symbol(
         get_default_asset_id(token.contract_id())
-    ).await;
+    ).await.unwrap();
     assert_eq!(
         result_symbol, 
         Some(String::from(symbol))
@@ -172,7 +172,7 @@ async fn test_src20_configurables() {
 
     let result_name = token.clone().call_name(
         get_default_asset_id(token.contract_id())
-    ).await;
+    ).await.unwrap();
 
     assert_eq!(
         result_name, 
@@ -181,7 +181,7 @@ async fn test_src20_configurables() {
 
     let result_decimals = token.clone().call_decimals(
         get_default_asset_id(token.contract_id())
-    ).await;
+    ).await.unwrap();
 
     assert_eq!(
         result_decimals, 
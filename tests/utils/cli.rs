@@ -0,0 +1,101 @@
+use fuels::{
+    prelude::*,
+    crypto::SecretKey,
+    types::{
+        AssetId,
+        ContractId,
+    },
+};
+use serde_json::Value;
+
+use std::str::FromStr;
+
+/* signing key of the wallet funded by the node, as passed to FUNI_SECRET_KEY */
+const CLI_SECRET_KEY: &str = "0707070707070707070707070707070707070707070707070707070707070707";
+
+/*
+  In-process node with a funded wallet, running the funi binary against it
+ */
+pub struct CliNode {
+    pub url: String,
+    pub wallet: WalletUnlocked,
+}
+
+impl CliNode {
+    pub async fn launch() -> Self {
+        let secret = SecretKey::from_str(CLI_SECRET_KEY).unwrap();
+        let mut wallet = WalletUnlocked::new_from_private_key(secret, None);
+        let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 16, 1_000_000_000_000);
+        let provider = setup_test_provider(coins, vec![], None, None).await.unwrap();
+        wallet.set_provider(provider.clone());
+        Self {
+            url: provider.url().to_string(),
+            wallet,
+        }
+    }
+
+    /*
+      Run `funi <args>` and return the JSON it printed, or the error JSON when it failed
+     */
+    pub async fn try_run(&self, args: &[impl AsRef<str>]) -> std::result::Result<Value, Value> {
+        let mut command = std::process::Command::new(env!("CARGO_BIN_EXE_funi"));
+        command
+            .env("FUNI_SECRET_KEY", CLI_SECRET_KEY)
+            .env_remove("FUNI_MANIFEST")
+            .env_remove("FUNI_SALT_SEED")
+            .arg("--network")
+            .arg(&self.url)
+            .args(args.iter().map(|arg| arg.as_ref()));
+        /* the node runs on this runtime, don't block it while the binary talks to it */
+        let output = tokio::task::spawn_blocking(move || command.output())
+            .await
+            .unwrap()
+            .unwrap();
+        if output.status.success() {
+            Ok(serde_json::from_slice(&output.stdout).unwrap())
+        } else {
+            Err(serde_json::from_slice(&output.stderr).unwrap())
+        }
+    }
+
+    pub async fn run(&self, args: &[impl AsRef<str>]) -> Value {
+        match self.try_run(args).await {
+            Ok(output) => output,
+            Err(error) => {
+                let args: Vec<&str> = args.iter().map(|arg| arg.as_ref()).collect();
+                panic!("funi {:?} failed: {}", args, error)
+            }
+        }
+    }
+
+    /*
+      Deploy a token with `supply` minted to the wallet, returns its asset id
+     */
+    pub async fn deploy_token(&self, symbol: &str, supply: u64) -> AssetId {
+        let output = self.run(&["deploy-token", "--symbol", symbol, "--supply", &supply.to_string()]).await;
+        asset_id(&output["asset_id"])
+    }
+
+    /*
+      Two tokens and their pair, returns (pair, token0, token1)
+     */
+    pub async fn deploy_pair(&self, supply: u64) -> (ContractId, AssetId, AssetId) {
+        let token_a = self.deploy_token("TKA", supply).await;
+        let token_b = self.deploy_token("TKB", supply).await;
+        let output = self.run(&[
+            "deploy-pair",
+            "--token0", &token_a.to_string(),
+            "--token1", &token_b.to_string(),
+        ]).await;
+        let (token0, token1) = funi_sdk::pair::sort_tokens(token_a, token_b);
+        (contract_id(&output["contract_id"]), token0, token1)
+    }
+}
+
+pub fn asset_id(value: &Value) -> AssetId {
+    AssetId::from_str(value.as_str().unwrap()).unwrap()
+}
+
+pub fn contract_id(value: &Value) -> ContractId {
+    ContractId::from_str(value.as_str().unwrap()).unwrap()
+}
@@ -0,0 +1,20 @@
+use fuels::prelude::*;
+
+use crate::utils::setup::{
+    FuniSwapV2Factory,
+    TxConfig,
+    get_wallet_provider_salt,
+};
+
+use crate::utils::gas::gas_log;
+
+use crate::utils::instance::ContractInstance;
+
+/*
+  Constructor of the FuniSwapV2Factory, it has no configurables
+ */
+pub async fn new_factory() -> ContractInstance<FuniSwapV2Factory<WalletUnlocked>> {
+    let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+    ContractInstance::<FuniSwapV2Factory<WalletUnlocked>>::deploy(wallet, salt, TxConfig::default()).await.unwrap()
+        .with_call_log(gas_log())
+}
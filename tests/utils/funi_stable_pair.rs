@@ -0,0 +1,34 @@
+use fuels::prelude::*;
+
+use crate::utils::setup::{
+    FuniSwapV2StablePair,
+    FuniSwapV2StablePairConfigurables,
+    TxConfig,
+    get_wallet_provider_salt,
+};
+
+use crate::utils::gas::gas_log;
+
+use crate::utils::instance::{
+    ContractInstance,
+    TestInstance,
+};
+
+impl TestInstance for ContractInstance<FuniSwapV2StablePair<WalletUnlocked>> {
+    type Configurables = FuniSwapV2StablePairConfigurables;
+
+    /*
+      Constructor of the FuniSwapV2StablePair
+     */
+    async fn new() -> Self {
+        let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+        Self::deploy(wallet, salt, TxConfig::default()).await.unwrap()
+            .with_call_log(gas_log())
+    }
+
+    async fn new_with_configurables(configurables: FuniSwapV2StablePairConfigurables) -> Self {
+        let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+        Self::deploy_with_configurables(wallet, salt, configurables, TxConfig::default()).await.unwrap()
+            .with_call_log(gas_log())
+    }
+}
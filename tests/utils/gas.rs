@@ -0,0 +1,24 @@
+use std::sync::OnceLock;
+
+use funi_sdk::instance::CallLog;
+
+/*
+  Gas samples of every call made by the test instances, read by the gas-report binary
+ */
+pub const GAS_SAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/gas/calls.jsonl");
+
+/*
+  Maximum gas per method, checked by the gas tests and `gas-report --thresholds`
+ */
+pub const GAS_THRESHOLDS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/gas_thresholds.json");
+
+/*
+  Call log shared by all the test instances of the run. The sink is
+  truncated once, when the first instance is deployed.
+ */
+pub fn gas_log() -> CallLog {
+    static GAS_LOG: OnceLock<CallLog> = OnceLock::new();
+    GAS_LOG
+        .get_or_init(|| CallLog::with_sink(GAS_SAMPLES).unwrap())
+        .clone()
+}
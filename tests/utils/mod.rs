@@ -1,5 +1,10 @@
 pub mod setup;
+pub mod gas;
 pub mod src20;
+pub mod src20_taxed;
 pub mod funi_pair;
+pub mod funi_stable_pair;
 pub mod funi_router02;
-pub mod instance;
\ No newline at end of file
+pub mod funi_factory;
+pub mod instance;
+pub mod cli;
\ No newline at end of file
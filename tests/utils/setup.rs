@@ -1,45 +1,51 @@
 use fuels::{
-    prelude::*, 
-    types::ContractId, 
-    crypto::SecretKey, 
+    prelude::*,
+    crypto::SecretKey,
+    test_helpers::ChainConfig,
+    tx::ConsensusParameters,
     types::{
-        AssetId,
-        Bytes32,
-        Bits256,
-        SizedAsciiString,
         Identity,
     }
 };
 
-// Load abi from json
-abigen!(
-    Contract(
-        name = "SRC20",
-        abi = "./SRC20/out/debug/src20-abi.json"
-    ),
-    Contract(
-        name = "FuniSwapV2Pair",
-        abi = "./FuniSwapV2Pair/out/debug/FuniSwapV2Pair-abi.json"
-    ),
-    Contract(
-        name = "FuniSwapV2Router02",
-        abi = "./FuniSwapV2Router02/out/debug/FuniSwapV2Router02-abi.json"
-    )
-);
-
+pub use funi_sdk::abi::*;
+pub use funi_sdk::config::{
+    TxConfig,
+    DEFAULT_GAS_LIMIT,
+};
+pub use funi_sdk::utils::{
+    get_asset_id,
+    get_default_asset_id,
+    predict_contract_id,
+    random_salt,
+    salt_for,
+    DEFAULT_SUB_ID,
+};
+pub use funi_sdk::pair::{
+    create_funi_pair_base_configurables,
+    create_funi_pair_configurables,
+};
+pub use funi_sdk::router::create_funi_router02_configurables;
+pub use funi_sdk::router::{
+    create_funi_router02_eth_configurables,
+    create_funi_router02_pair_configurables,
+};
+pub use funi_sdk::stable_pair::create_funi_stable_pair_configurables;
+pub use funi_sdk::taxed_token::create_src20_taxed_configurables;
+pub use funi_sdk::token::create_src20_configurables;
 
-use rand::Rng;
 use std::str::FromStr;
-use sha2::{Digest, Sha256};
-
-pub const DEFAULT_GAS_LIMIT: u64 = 400000;
-pub const DEFAULT_SUB_ID: Bits256 = Bits256([0; 32]);
 
 pub const SECRECT_KEY: &str = "<YOUR_SECRECT_KEY_HERE>";
 
 pub const FUEL_NETWORK: &str = "127.0.0.1:4000";
 //pub const FUEL_NETWORK: &str = "testnet.fuel.network";
 
+// Seed the salts of labelled deployments are derived from. A contract ID can
+// only be deployed once per node, so unless FUNI_SALT_SEED is set each run
+// draws its own seed.
+static SALT_SEED: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
 /*
  * ---- Generic Wallet creation
  */
@@ -57,194 +63,64 @@ pub async fn get_wallet_provider_salt() -> (Provider, WalletUnlocked, Salt) {
 
     let wallet = WalletUnlocked::new_from_private_key(secret, Some(provider.clone()));
 
-    // Generate a random 32-byte array
-    let mut rng = rand::thread_rng();
-    let mut bytes = [0u8; 32];
-    rng.fill(&mut bytes);
-
-    let salt = Salt::new(bytes);
-
-    (provider, wallet, salt)
+    (provider, wallet, random_salt())
 }
 
 /*
- * ---- SRC20 setup functions
+    Same as get_wallet_provider_salt(), but the salt is derived from the seed
+    and "<test name>:<label>", so each test gets its own reproducible IDs.
  */
-
-pub async fn get_src20_contract_instance() -> (SRC20<WalletUnlocked>, ContractId, WalletUnlocked, AssetId) {
-    
-    let (provider, wallet, salt) = get_wallet_provider_salt().await;
-
-    let id = Contract::load_from(
-        "./SRC20/out/debug/src20.bin",
-        LoadConfiguration::default().with_salt(salt),
-    )
-    .unwrap()
-    .deploy(&wallet, TxPolicies::default().with_script_gas_limit(400000).with_max_fee(400000))
-    .await
-    .unwrap();
-
-    let instance = SRC20::new(id.clone(), wallet.clone());
-    let base_asset_id = provider.base_asset_id();
-
-    (instance, id.into(), wallet, *base_asset_id)
-}
-
-pub async fn get_src20_contract_instance_with_configurables(configurables: SRC20Configurables) -> (SRC20<WalletUnlocked>, ContractId, WalletUnlocked, AssetId) {
-    
-    let (provider, wallet, salt) = get_wallet_provider_salt().await;
-
-    let id = Contract::load_from(
-        "./SRC20/out/debug/src20.bin",
-        LoadConfiguration::default()
-        .with_salt(salt)
-        .with_configurables(configurables),
-    )
-    .unwrap()
-    .deploy(&wallet, TxPolicies::default().with_script_gas_limit(400000).with_max_fee(400000))
-    .await
-    .unwrap();
-
-    let instance = SRC20::new(id.clone(), wallet.clone());
-    let base_asset_id = provider.base_asset_id();
-
-    (instance, id.into(), wallet, *base_asset_id)
-}
-
-pub fn create_src20_configurables(name: &str, symbol: &str, decimals: u8) -> SRC20Configurables {
-    let name_configurable: SizedAsciiString<5> = name.try_into().unwrap();
-    let symbol_configurable: SizedAsciiString<3> = symbol.try_into().unwrap();
-
-    SRC20Configurables::default()
-    .with_name(name_configurable).unwrap()
-    .with_symbol(symbol_configurable).unwrap()
-    .with_decimals(decimals).unwrap()
+pub async fn get_wallet_provider_salt_for(label: &str) -> (Provider, WalletUnlocked, Salt) {
+    let (provider, wallet, _salt) = get_wallet_provider_salt().await;
+    let label = format!("{}:{}", test_name(), label);
+    (provider, wallet, salt_for(Some(&salt_seed()), &label))
 }
 
 /*
- * ---- FuniSwapV2Pair Setup Functions
+    FUNI_SALT_SEED when set, a random seed shared by the whole run otherwise
  */
-
-pub async fn get_funi_pair_contract_instance() -> (FuniSwapV2Pair<WalletUnlocked>, ContractId, WalletUnlocked, AssetId) {
-    
-    let (provider, wallet, salt) = get_wallet_provider_salt().await;
-
-    let id = Contract::load_from(
-        "./FuniSwapV2Pair/out/debug/FuniSwapV2Pair.bin",
-        LoadConfiguration::default().with_salt(salt),
-    )
-    .unwrap()
-    .deploy(&wallet, TxPolicies::default().with_script_gas_limit(400000).with_max_fee(400000))
-    .await
-    .unwrap();
-
-    let instance = FuniSwapV2Pair::new(id.clone(), wallet.clone());
-    let base_asset_id = provider.base_asset_id();
-
-    (instance, id.into(), wallet, *base_asset_id)
-}
-
-pub async fn get_funi_pair_contract_instance_with_configurables(configurables: FuniSwapV2PairConfigurables) -> (
-    FuniSwapV2Pair<WalletUnlocked>, 
-    ContractId, 
-    WalletUnlocked, 
-    AssetId
-) {
-    let (provider, wallet, salt) = get_wallet_provider_salt().await;
-
-    let id = Contract::load_from(
-        "./FuniSwapV2Pair/out/debug/FuniSwapV2Pair.bin",
-        LoadConfiguration::default()
-        .with_salt(salt)
-        .with_configurables(configurables),
-    )
-    .unwrap()
-    .deploy(&wallet, TxPolicies::default().with_script_gas_limit(400000).with_max_fee(400000))
-    .await
-    .unwrap();
-
-    let instance = FuniSwapV2Pair::new(id.clone(), wallet.clone());
-    let base_asset_id = provider.base_asset_id();
-
-    (instance, id.into(), wallet, *base_asset_id)
-}
-
-pub fn create_funi_pair_configurables(token0: AssetId, token1: AssetId) -> FuniSwapV2PairConfigurables {
-    FuniSwapV2PairConfigurables::default()
-    .with_token0(token0).unwrap()
-    .with_token1(token1).unwrap()
+pub fn salt_seed() -> String {
+    SALT_SEED.get_or_init(|| {
+        std::env::var("FUNI_SALT_SEED").unwrap_or_else(|_| random_salt().to_string())
+    }).clone()
 }
 
 /*
- * ---- FuniSwapV2Router02 Setup Functions
+    Path of the running test, e.g. "manifest::deployments::test_manifest_reattach_token".
+    The test harness names the thread of each test after it, and #[tokio::test]
+    runs the test on that thread.
  */
-
-pub async fn get_funi_router02_contract_instance() -> (FuniSwapV2Router02<WalletUnlocked>, ContractId, WalletUnlocked, AssetId) {
-    
-    let (provider, wallet, salt) = get_wallet_provider_salt().await;
-
-    let id = Contract::load_from(
-        "./FuniSwapV2Router02/out/debug/FuniSwapV2Router02.bin",
-        LoadConfiguration::default().with_salt(salt),
-    )
-    .unwrap()
-    .deploy(&wallet, TxPolicies::default().with_script_gas_limit(400000).with_max_fee(400000))
-    .await
-    .unwrap();
-
-    let instance = FuniSwapV2Router02::new(id.clone(), wallet.clone());
-    let base_asset_id = provider.base_asset_id();
-
-    (instance, id.into(), wallet, *base_asset_id)
-}
-
-pub async fn get_funi_router02_contract_instance_with_configurables(configurables: FuniSwapV2Router02Configurables) -> (
-    FuniSwapV2Router02<WalletUnlocked>, 
-    ContractId, 
-    WalletUnlocked, 
-    AssetId
-) {
-    let (provider, wallet, salt) = get_wallet_provider_salt().await;
-
-    let id = Contract::load_from(
-        "./FuniSwapV2Router02/out/debug/FuniSwapV2Router02.bin",
-        LoadConfiguration::default()
-        .with_salt(salt)
-        .with_configurables(configurables),
-    )
-    .unwrap()
-    .deploy(&wallet, TxPolicies::default().with_script_gas_limit(400000).with_max_fee(400000))
-    .await
-    .unwrap();
-
-    let instance = FuniSwapV2Router02::new(id.clone(), wallet.clone());
-    let base_asset_id = provider.base_asset_id();
-
-    (instance, id.into(), wallet, *base_asset_id)
+pub fn test_name() -> String {
+    String::from(std::thread::current().name().unwrap_or("main"))
 }
 
-pub fn create_funi_router02_configurables(token0: AssetId, token1: AssetId) -> FuniSwapV2Router02Configurables {
-    FuniSwapV2Router02Configurables::default()
-    .with_token0(token0).unwrap()
-    .with_token1(token1).unwrap()
+/*
+    Wallet of an in-process node whose base asset is the given one, for the
+    checks depending on the chain configuration. The coins are in the base asset.
+ */
+pub async fn get_wallet_with_base_asset(base_asset_id: AssetId) -> WalletUnlocked {
+    let mut consensus_parameters = ConsensusParameters::default();
+    consensus_parameters.set_base_asset_id(base_asset_id);
+    let chain_config = ChainConfig {
+        consensus_parameters,
+        ..ChainConfig::local_testnet()
+    };
+    let wallets_config = WalletsConfig::new_multiple_assets(1, vec![AssetConfig {
+        id: base_asset_id,
+        num_coins: 1,
+        coin_amount: 1_000_000_000_000,
+    }]);
+    launch_custom_provider_and_get_wallets(wallets_config, None, Some(chain_config))
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
 }
 
 /*
  * ---- Generic Utils
  */
 
-pub fn get_asset_id(sub_id: Bytes32, contract: ContractId) -> AssetId {
-    let mut hasher = Sha256::new();
-    hasher.update(*contract);
-    hasher.update(*sub_id);
-    AssetId::new(*Bytes32::from(<[u8; 32]>::from(hasher.finalize())))
-}
-
-pub fn get_default_asset_id(contract: ContractId) -> AssetId {
-    let default_sub_id = Bytes32::from([0u8; 32]);
-    get_asset_id(default_sub_id, contract)
-}
-
 pub async fn get_deployer_identity() -> Identity {
     let (_provider, wallet, _salt) = get_wallet_provider_salt().await;
     Identity::Address(
@@ -253,5 +129,3 @@ pub async fn get_deployer_identity() -> Identity {
         )
     )
 }
-
-
@@ -0,0 +1,34 @@
+use fuels::prelude::*;
+
+use crate::utils::setup::{
+    SRC20Taxed,
+    SRC20TaxedConfigurables,
+    TxConfig,
+    get_wallet_provider_salt,
+};
+
+use crate::utils::gas::gas_log;
+
+use crate::utils::instance::{
+    ContractInstance,
+    TestInstance,
+};
+
+impl TestInstance for ContractInstance<SRC20Taxed<WalletUnlocked>> {
+    type Configurables = SRC20TaxedConfigurables;
+
+    /*
+      Constructor of the SRC20Taxed token
+     */
+    async fn new() -> Self {
+        let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+        Self::deploy(wallet, salt, TxConfig::default()).await.unwrap()
+            .with_call_log(gas_log())
+    }
+
+    async fn new_with_configurables(configurables: SRC20TaxedConfigurables) -> Self {
+        let (_provider, wallet, salt) = get_wallet_provider_salt().await;
+        Self::deploy_with_configurables(wallet, salt, configurables, TxConfig::default()).await.unwrap()
+            .with_call_log(gas_log())
+    }
+}